@@ -0,0 +1,21 @@
+-- Add migration script here
+ALTER TABLE Bookings
+    ADD COLUMN monthly_rent BIGINT UNSIGNED NOT NULL DEFAULT 0 AFTER check_out;
+
+CREATE TABLE Invoices (
+    id BINARY(16) PRIMARY KEY,
+    booking_id BINARY(16) NOT NULL,
+    period_start DATE NOT NULL,
+    amount BIGINT UNSIGNED NOT NULL,
+    due_date DATE NOT NULL,
+    status ENUM('PENDING', 'PAID', 'OVERDUE', 'CANCELLED') NOT NULL DEFAULT 'PENDING',
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+        ON UPDATE CURRENT_TIMESTAMP,
+    UNIQUE (booking_id, period_start),
+    FOREIGN KEY (booking_id)
+        REFERENCES Bookings(id)
+        ON DELETE RESTRICT
+);
+
+CREATE INDEX idx_invoices_status_due_date ON Invoices(status, due_date);
@@ -0,0 +1,5 @@
+-- Add migration script here
+ALTER TABLE Rooms
+    ADD COLUMN monthly_rent BIGINT UNSIGNED NOT NULL DEFAULT 0 AFTER room_vacancy,
+    ADD COLUMN deposit BIGINT UNSIGNED NULL AFTER monthly_rent,
+    ADD COLUMN currency CHAR(3) NOT NULL DEFAULT 'IDR' AFTER deposit;
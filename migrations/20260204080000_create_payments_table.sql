@@ -0,0 +1,25 @@
+-- Add migration script here
+ALTER TABLE Invoices
+    ADD COLUMN paid_amount BIGINT UNSIGNED NOT NULL DEFAULT 0 AFTER amount;
+
+CREATE TABLE Payments (
+    id BINARY(16) PRIMARY KEY,
+    invoice_id BINARY(16) NOT NULL,
+    amount BIGINT UNSIGNED NOT NULL,
+    method ENUM('CASH', 'BANK_TRANSFER') NOT NULL,
+    reference VARCHAR(100),
+    paid_at DATETIME NOT NULL,
+    recorded_by BINARY(16),
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+        ON UPDATE CURRENT_TIMESTAMP,
+    FOREIGN KEY (invoice_id)
+        REFERENCES Invoices(id)
+        ON DELETE RESTRICT,
+    FOREIGN KEY (recorded_by)
+        REFERENCES Users(id)
+        ON DELETE SET NULL,
+    CHECK (amount > 0)
+);
+
+CREATE INDEX idx_payments_invoice_id ON Payments(invoice_id);
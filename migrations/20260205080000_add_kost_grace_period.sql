@@ -0,0 +1,3 @@
+-- Add migration script here
+ALTER TABLE Kosts
+    ADD COLUMN grace_period_days INT UNSIGNED NOT NULL DEFAULT 3 AFTER kost_desc;
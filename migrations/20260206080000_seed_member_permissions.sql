@@ -0,0 +1,17 @@
+-- Add migration script here
+INSERT INTO Permissions (id, name)
+VALUES
+(UUID_TO_BIN(UUID()), 'booking:view_own'),
+(UUID_TO_BIN(UUID()), 'invoice:view_own'),
+(UUID_TO_BIN(UUID()), 'payment:view_own');
+
+INSERT INTO Role_Permissions (role_id, permission_id)
+SELECT r.id, p.id
+FROM Roles r
+JOIN Permissions p
+WHERE r.name IN ('ADMIN', 'MEMBER')
+    AND p.name IN (
+        'booking:view_own',
+        'invoice:view_own',
+        'payment:view_own'
+    );
@@ -0,0 +1,46 @@
+-- Add migration script here
+CREATE TABLE Booking_Requests (
+    id BINARY(16) PRIMARY KEY,
+    room_id BINARY(16) NOT NULL,
+    user_id BINARY(16) NOT NULL,
+    check_in DATETIME NOT NULL,
+    check_out DATETIME,
+    message VARCHAR(500),
+    status ENUM('REQUESTED', 'APPROVED', 'REJECTED', 'EXPIRED') NOT NULL DEFAULT 'REQUESTED',
+    rejection_reason VARCHAR(500),
+    booking_id BINARY(16),
+    decided_by BINARY(16),
+    decided_at TIMESTAMP NULL,
+    expires_at DATETIME NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+        ON UPDATE CURRENT_TIMESTAMP,
+    FOREIGN KEY (room_id)
+        REFERENCES Rooms(id)
+        ON DELETE CASCADE,
+    FOREIGN KEY (user_id)
+        REFERENCES Users(id)
+        ON DELETE CASCADE,
+    FOREIGN KEY (booking_id)
+        REFERENCES Bookings(id)
+        ON DELETE SET NULL,
+    FOREIGN KEY (decided_by)
+        REFERENCES Users(id)
+        ON DELETE SET NULL,
+    CHECK (check_out IS NULL or check_out > check_in)
+);
+
+CREATE INDEX idx_booking_requests_room_id ON Booking_Requests(room_id);
+CREATE INDEX idx_booking_requests_user_id ON Booking_Requests(user_id);
+CREATE INDEX idx_booking_requests_status_expires_at ON Booking_Requests(status, expires_at);
+
+INSERT INTO Permissions (id, name)
+VALUES
+(UUID_TO_BIN(UUID()), 'booking:request');
+
+INSERT INTO Role_Permissions (role_id, permission_id)
+SELECT r.id, p.id
+FROM Roles r
+JOIN Permissions p
+WHERE r.name IN ('ADMIN', 'MEMBER')
+    AND p.name = 'booking:request';
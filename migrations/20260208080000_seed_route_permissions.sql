@@ -0,0 +1,42 @@
+-- Add migration script here
+INSERT INTO Permissions (id, name)
+VALUES
+(UUID_TO_BIN(UUID()), 'booking:create'),
+(UUID_TO_BIN(UUID()), 'booking:update'),
+(UUID_TO_BIN(UUID()), 'booking:delete'),
+(UUID_TO_BIN(UUID()), 'booking:view'),
+(UUID_TO_BIN(UUID()), 'booking_request:review'),
+(UUID_TO_BIN(UUID()), 'invoice:create'),
+(UUID_TO_BIN(UUID()), 'invoice:update'),
+(UUID_TO_BIN(UUID()), 'invoice:view'),
+(UUID_TO_BIN(UUID()), 'payment:create'),
+(UUID_TO_BIN(UUID()), 'payment:delete'),
+(UUID_TO_BIN(UUID()), 'payment:view'),
+(UUID_TO_BIN(UUID()), 'dashboard:view');
+
+INSERT INTO Role_Permissions (role_id, permission_id)
+SELECT r.id, p.id
+FROM Roles r
+JOIN Permissions p
+WHERE r.name = 'OWNER'
+    AND p.name IN (
+        'booking:create',
+        'booking:update',
+        'booking:delete',
+        'booking:view',
+        'booking_request:review',
+        'invoice:create',
+        'invoice:update',
+        'invoice:view',
+        'payment:create',
+        'payment:delete',
+        'payment:view',
+        'dashboard:view'
+    );
+
+-- ADMIN keeps every permission, including the ones added after the first seed
+INSERT IGNORE INTO Role_Permissions (role_id, permission_id)
+SELECT r.id, p.id
+FROM Roles r
+JOIN Permissions p
+WHERE r.name = 'ADMIN';
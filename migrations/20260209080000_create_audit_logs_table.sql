@@ -0,0 +1,43 @@
+-- Add migration script here
+CREATE TABLE Audit_Logs (
+    id BINARY(16) PRIMARY KEY,
+    actor_id BINARY(16),
+    action VARCHAR(100) NOT NULL,
+    target_type VARCHAR(50) NOT NULL,
+    target_id BINARY(16),
+    details JSON,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (actor_id)
+        REFERENCES Users(id)
+        ON DELETE SET NULL
+);
+
+CREATE INDEX idx_audit_logs_created_at ON Audit_Logs(created_at);
+CREATE INDEX idx_audit_logs_target ON Audit_Logs(target_type, target_id);
+
+INSERT INTO Permissions (id, name)
+VALUES
+(UUID_TO_BIN(UUID()), 'role:create'),
+(UUID_TO_BIN(UUID()), 'role:update'),
+(UUID_TO_BIN(UUID()), 'role:delete'),
+(UUID_TO_BIN(UUID()), 'role:view'),
+(UUID_TO_BIN(UUID()), 'permission:create'),
+(UUID_TO_BIN(UUID()), 'permission:delete'),
+(UUID_TO_BIN(UUID()), 'permission:view'),
+(UUID_TO_BIN(UUID()), 'audit:view');
+
+INSERT INTO Role_Permissions (role_id, permission_id)
+SELECT r.id, p.id
+FROM Roles r
+JOIN Permissions p
+WHERE r.name = 'ADMIN'
+    AND p.name IN (
+        'role:create',
+        'role:update',
+        'role:delete',
+        'role:view',
+        'permission:create',
+        'permission:delete',
+        'permission:view',
+        'audit:view'
+    );
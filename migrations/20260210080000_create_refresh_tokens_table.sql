@@ -0,0 +1,27 @@
+-- Add migration script here
+CREATE TABLE Refresh_Tokens (
+    id BINARY(16) PRIMARY KEY,
+    user_id BINARY(16) NOT NULL,
+    token_hash CHAR(64) NOT NULL UNIQUE,
+    expires_at DATETIME NOT NULL,
+    revoked_at DATETIME,
+    replaced_by BINARY(16),
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (user_id)
+        REFERENCES Users(id)
+        ON DELETE CASCADE
+);
+
+CREATE INDEX idx_refresh_tokens_user_id ON Refresh_Tokens(user_id);
+
+CREATE TABLE Revoked_Tokens (
+    jti BINARY(16) PRIMARY KEY,
+    user_id BINARY(16) NOT NULL,
+    expires_at DATETIME NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (user_id)
+        REFERENCES Users(id)
+        ON DELETE CASCADE
+);
+
+CREATE INDEX idx_revoked_tokens_expires_at ON Revoked_Tokens(expires_at);
@@ -0,0 +1,3 @@
+-- Add migration script here
+ALTER TABLE Users
+    ADD COLUMN token_version INT UNSIGNED NOT NULL DEFAULT 0 AFTER role_id;
@@ -0,0 +1,15 @@
+-- Add migration script here
+CREATE TABLE Password_Resets (
+    id BINARY(16) PRIMARY KEY,
+    user_id BINARY(16) NOT NULL,
+    token_hash CHAR(64) NOT NULL UNIQUE,
+    expires_at DATETIME NOT NULL,
+    used_at DATETIME,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (user_id)
+        REFERENCES Users(id)
+        ON DELETE CASCADE
+);
+
+CREATE INDEX idx_password_resets_user_id ON Password_Resets(user_id);
+CREATE INDEX idx_password_resets_expires_at ON Password_Resets(expires_at);
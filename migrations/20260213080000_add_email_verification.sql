@@ -0,0 +1,32 @@
+-- Add migration script here
+ALTER TABLE Users
+    ADD COLUMN email_verified_at DATETIME AFTER email;
+
+-- Accounts created before verification existed keep working
+UPDATE Users SET email_verified_at = UTC_TIMESTAMP();
+
+CREATE TABLE Email_Verifications (
+    id BINARY(16) PRIMARY KEY,
+    user_id BINARY(16) NOT NULL,
+    token_hash CHAR(64) NOT NULL UNIQUE,
+    expires_at DATETIME NOT NULL,
+    used_at DATETIME,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (user_id)
+        REFERENCES Users(id)
+        ON DELETE CASCADE
+);
+
+CREATE INDEX idx_email_verifications_user_id ON Email_Verifications(user_id);
+CREATE INDEX idx_email_verifications_expires_at ON Email_Verifications(expires_at);
+
+INSERT INTO Permissions (id, name)
+VALUES
+(UUID_TO_BIN(UUID()), 'user:resend_verification');
+
+INSERT INTO Role_Permissions (role_id, permission_id)
+SELECT r.id, p.id
+FROM Roles r
+JOIN Permissions p
+WHERE r.name = 'ADMIN'
+    AND p.name = 'user:resend_verification';
@@ -0,0 +1,13 @@
+-- Add migration script here
+-- Failed logins per email and per client IP, scope is 'email' or 'ip'
+CREATE TABLE Login_Throttles (
+    scope VARCHAR(10) NOT NULL,
+    identifier VARCHAR(255) NOT NULL,
+    failed_count INT UNSIGNED NOT NULL DEFAULT 0,
+    lockout_count INT UNSIGNED NOT NULL DEFAULT 0,
+    last_failed_at DATETIME NOT NULL,
+    locked_until DATETIME,
+    PRIMARY KEY (scope, identifier)
+);
+
+CREATE INDEX idx_login_throttles_last_failed_at ON Login_Throttles(last_failed_at);
@@ -0,0 +1,45 @@
+-- Add migration script here
+CREATE TABLE Owner_Applications (
+    id BINARY(16) PRIMARY KEY,
+    user_id BINARY(16) NOT NULL,
+    business_name VARCHAR(150) NOT NULL,
+    business_address VARCHAR(255) NOT NULL,
+    phone VARCHAR(30) NOT NULL,
+    description VARCHAR(1000),
+    status ENUM('PENDING', 'APPROVED', 'REJECTED') NOT NULL DEFAULT 'PENDING',
+    rejection_reason VARCHAR(500),
+    decided_by BINARY(16),
+    decided_at TIMESTAMP NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+        ON UPDATE CURRENT_TIMESTAMP,
+    FOREIGN KEY (user_id)
+        REFERENCES Users(id)
+        ON DELETE CASCADE,
+    FOREIGN KEY (decided_by)
+        REFERENCES Users(id)
+        ON DELETE SET NULL
+);
+
+CREATE INDEX idx_owner_applications_user_id ON Owner_Applications(user_id);
+CREATE INDEX idx_owner_applications_status ON Owner_Applications(status);
+
+INSERT INTO Permissions (id, name)
+VALUES
+(UUID_TO_BIN(UUID()), 'owner_application:create'),
+(UUID_TO_BIN(UUID()), 'owner_application:review');
+
+-- Only members apply, admins review
+INSERT INTO Role_Permissions (role_id, permission_id)
+SELECT r.id, p.id
+FROM Roles r
+JOIN Permissions p
+WHERE r.name = 'MEMBER'
+    AND p.name = 'owner_application:create';
+
+INSERT INTO Role_Permissions (role_id, permission_id)
+SELECT r.id, p.id
+FROM Roles r
+JOIN Permissions p
+WHERE r.name = 'ADMIN'
+    AND p.name = 'owner_application:review';
@@ -0,0 +1,11 @@
+-- Add migration script here
+INSERT INTO Permissions (id, name)
+VALUES
+(UUID_TO_BIN(UUID()), 'user:change_role');
+
+INSERT INTO Role_Permissions (role_id, permission_id)
+SELECT r.id, p.id
+FROM Roles r
+JOIN Permissions p
+WHERE r.name = 'ADMIN'
+    AND p.name = 'user:change_role';
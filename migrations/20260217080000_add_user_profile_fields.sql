@@ -0,0 +1,4 @@
+-- Add migration script here
+ALTER TABLE Users
+    ADD COLUMN phone VARCHAR(30) AFTER email_verified_at,
+    ADD COLUMN avatar_url VARCHAR(500) AFTER phone;
@@ -0,0 +1,313 @@
+use std::{
+    env,
+    fmt::Display,
+    net::{IpAddr, SocketAddr},
+    path::PathBuf,
+    str::FromStr,
+    sync::OnceLock,
+    time::Duration,
+};
+
+use axum::http::HeaderValue;
+use lettre::message::Mailbox;
+
+// Shortest JWT secret accepted, 32 bytes is the HS256 key size
+const MIN_JWT_SECRET_LEN: usize = 32;
+
+// Secrets from examples and old defaults, never accepted
+const KNOWN_WEAK_SECRETS: [&str; 4] = ["kost_management", "secret", "changeme", "jwt_secret"];
+
+static CONFIG: OnceLock<AppConfig> = OnceLock::new();
+
+// Origins the browser is allowed to call the API from
+#[derive(Debug, Clone)]
+pub enum CorsOrigins {
+    Any,
+    List(Vec<HeaderValue>),
+}
+
+// Where outgoing emails go
+#[derive(Debug, Clone)]
+pub enum MailDriver {
+    // Print the emails to the console, for local development
+    Log,
+    Smtp(SmtpConfig),
+}
+
+#[derive(Debug, Clone)]
+pub struct SmtpConfig {
+    pub host: String,
+    pub port: u16,
+    pub username: Option<String>,
+    pub password: Option<String>,
+}
+
+// Where uploaded files are stored
+#[derive(Debug, Clone)]
+pub enum StorageDriver {
+    Local(LocalStorageConfig),
+}
+
+#[derive(Debug, Clone)]
+pub struct LocalStorageConfig {
+    // Directory on disk
+    pub upload_dir: PathBuf,
+    // Prefix of the file URLs, a path is served by the app, a full URL points to another server
+    pub public_url: String,
+}
+
+// Application settings, read from the environment once at startup
+#[derive(Debug, Clone)]
+pub struct AppConfig {
+    pub database_url: String,
+    pub bind_addr: SocketAddr,
+    pub cors_origins: CorsOrigins,
+    pub jwt_secret: String,
+    pub access_token_minutes: i64,
+    pub refresh_token_days: i64,
+    pub db_max_connections: u32,
+    pub db_min_connections: u32,
+    pub db_acquire_timeout: Duration,
+    pub invoice_job_interval: Duration,
+    pub overdue_job_interval: Duration,
+    pub booking_request_job_interval: Duration,
+    pub storage_driver: StorageDriver,
+    pub mail_driver: MailDriver,
+    pub mail_from: Mailbox,
+    // Base URL of the frontend, links in emails point there
+    pub frontend_url: String,
+    pub password_reset_minutes: i64,
+    pub email_verification_hours: i64,
+    // Take the client IP from X-Forwarded-For, only when the app runs behind a proxy that sets it
+    pub trust_proxy_headers: bool,
+    pub login_max_attempts: u32,
+    pub login_ip_max_attempts: u32,
+    pub login_attempt_window: Duration,
+    pub login_lockout_base: Duration,
+    pub login_lockout_max: Duration,
+}
+
+impl AppConfig {
+    // Read every setting, all problems are returned together so they can be fixed in one go
+    pub fn from_env() -> Result<Self, Vec<String>> {
+        let mut errors = Vec::new();
+
+        let database_url = required("DATABASE_URL", &mut errors);
+
+        let host: IpAddr = parse("APP_HOST", "127.0.0.1".parse().unwrap(), &mut errors);
+        let port: u16 = parse("APP_PORT", 3001, &mut errors);
+
+        let cors_origins = cors_origins(&mut errors);
+
+        let jwt_secret = required("JWT_SECRET_KEY", &mut errors);
+        if !jwt_secret.is_empty() {
+            if KNOWN_WEAK_SECRETS.contains(&jwt_secret.to_lowercase().as_str()) {
+                errors.push("JWT_SECRET_KEY is a default value, generate a random secret".to_string());
+            } else if jwt_secret.len() < MIN_JWT_SECRET_LEN {
+                errors.push(format!("JWT_SECRET_KEY must be at least {} characters", MIN_JWT_SECRET_LEN));
+            }
+        }
+
+        let access_token_minutes = in_range("ACCESS_TOKEN_TTL_MINUTES", 15, 1, 24 * 60, &mut errors);
+        let refresh_token_days = in_range("REFRESH_TOKEN_TTL_DAYS", 30, 1, 365, &mut errors);
+
+        let db_max_connections = in_range("DB_MAX_CONNECTIONS", 10, 1, 1000, &mut errors);
+        let db_min_connections = in_range("DB_MIN_CONNECTIONS", 0, 0, 1000, &mut errors);
+        if db_min_connections > db_max_connections {
+            errors.push("DB_MIN_CONNECTIONS cannot be more than DB_MAX_CONNECTIONS".to_string());
+        }
+        let db_acquire_timeout = in_range("DB_ACQUIRE_TIMEOUT_SECS", 30, 1, 600, &mut errors);
+
+        let invoice_job_interval = in_range("INVOICE_JOB_INTERVAL_SECS", 3600, 1, 7 * 24 * 60 * 60, &mut errors);
+        let overdue_job_interval = in_range("OVERDUE_JOB_INTERVAL_SECS", 3600, 1, 7 * 24 * 60 * 60, &mut errors);
+        let booking_request_job_interval = in_range("BOOKING_REQUEST_JOB_INTERVAL_SECS", 900, 1, 7 * 24 * 60 * 60, &mut errors);
+
+        let storage_driver = storage_driver(&mut errors);
+
+        let mail_driver = mail_driver(&mut errors);
+        let mail_from: Mailbox = parse("MAIL_FROM", "Kost Management <no-reply@localhost>".parse().unwrap(), &mut errors);
+        let frontend_url = env::var("APP_FRONTEND_URL")
+            .unwrap_or_else(|_| "http://localhost:3000".to_string())
+            .trim_end_matches('/')
+            .to_string();
+        let password_reset_minutes = in_range("PASSWORD_RESET_TTL_MINUTES", 60, 5, 24 * 60, &mut errors);
+        let email_verification_hours = in_range("EMAIL_VERIFICATION_TTL_HOURS", 48, 1, 7 * 24, &mut errors);
+
+        let trust_proxy_headers = parse("TRUST_PROXY_HEADERS", false, &mut errors);
+        let login_max_attempts = in_range("LOGIN_MAX_ATTEMPTS", 5, 1, 100, &mut errors);
+        let login_ip_max_attempts = in_range("LOGIN_IP_MAX_ATTEMPTS", 20, 1, 1000, &mut errors);
+        let login_attempt_window = in_range("LOGIN_ATTEMPT_WINDOW_SECS", 900, 1, 24 * 60 * 60, &mut errors);
+        let login_lockout_base = in_range("LOGIN_LOCKOUT_SECS", 60, 1, 24 * 60 * 60, &mut errors);
+        let login_lockout_max = in_range("LOGIN_LOCKOUT_MAX_SECS", 24 * 60 * 60, 1, 7 * 24 * 60 * 60, &mut errors);
+        if login_lockout_base > login_lockout_max {
+            errors.push("LOGIN_LOCKOUT_SECS cannot be more than LOGIN_LOCKOUT_MAX_SECS".to_string());
+        }
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        Ok(Self {
+            database_url,
+            bind_addr: SocketAddr::new(host, port),
+            cors_origins,
+            jwt_secret,
+            access_token_minutes,
+            refresh_token_days,
+            db_max_connections,
+            db_min_connections,
+            db_acquire_timeout: Duration::from_secs(db_acquire_timeout),
+            invoice_job_interval: Duration::from_secs(invoice_job_interval),
+            overdue_job_interval: Duration::from_secs(overdue_job_interval),
+            booking_request_job_interval: Duration::from_secs(booking_request_job_interval),
+            storage_driver,
+            mail_driver,
+            mail_from,
+            frontend_url,
+            password_reset_minutes,
+            email_verification_hours,
+            trust_proxy_headers,
+            login_max_attempts,
+            login_ip_max_attempts,
+            login_attempt_window: Duration::from_secs(login_attempt_window),
+            login_lockout_base: Duration::from_secs(login_lockout_base),
+            login_lockout_max: Duration::from_secs(login_lockout_max),
+        })
+    }
+}
+
+// Load the config once, the server does not start with an invalid config
+pub fn init() -> &'static AppConfig {
+    CONFIG.get_or_init(|| match AppConfig::from_env() {
+        Ok(config) => config,
+        Err(errors) => {
+            eprintln!("Invalid configuration:");
+            for error in errors {
+                eprintln!("  - {}", error);
+            }
+            std::process::exit(1);
+        }
+    })
+}
+
+// Get the config loaded by init
+pub fn get() -> &'static AppConfig {
+    CONFIG.get().expect("AppConfig is not initialized, call config::app_config::init first")
+}
+
+fn required(key: &str, errors: &mut Vec<String>) -> String {
+    match env::var(key) {
+        Ok(value) if !value.trim().is_empty() => value,
+        _ => {
+            errors.push(format!("{} is not set", key));
+            String::new()
+        }
+    }
+}
+
+fn parse<T>(key: &str, default: T, errors: &mut Vec<String>) -> T
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    match env::var(key) {
+        Ok(value) => match value.trim().parse() {
+            Ok(value) => value,
+            Err(e) => {
+                errors.push(format!("{} is not valid: {}", key, e));
+                default
+            }
+        },
+        Err(_) => default,
+    }
+}
+
+fn in_range<T>(key: &str, default: T, min: T, max: T, errors: &mut Vec<String>) -> T
+where
+    T: FromStr + PartialOrd + Display + Copy,
+    T::Err: Display,
+{
+    let value = parse(key, default, errors);
+
+    if value < min || value > max {
+        errors.push(format!("{} must be between {} and {}", key, min, max));
+    }
+
+    value
+}
+
+// CORS_ALLOWED_ORIGINS is a comma separated list of origins, or * to allow any origin
+fn cors_origins(errors: &mut Vec<String>) -> CorsOrigins {
+    let value = env::var("CORS_ALLOWED_ORIGINS").unwrap_or_else(|_| "*".to_string());
+
+    if value.trim() == "*" {
+        return CorsOrigins::Any;
+    }
+
+    let mut origins = Vec::new();
+
+    for origin in value.split(',').map(str::trim).filter(|o| !o.is_empty()) {
+        match HeaderValue::from_str(origin) {
+            Ok(origin) => origins.push(origin),
+            Err(_) => errors.push(format!("CORS_ALLOWED_ORIGINS has an invalid origin: {}", origin)),
+        }
+    }
+
+    if origins.is_empty() {
+        errors.push("CORS_ALLOWED_ORIGINS must be * or a list of origins".to_string());
+    }
+
+    CorsOrigins::List(origins)
+}
+
+// STORAGE_DRIVER is "local" for now, with UPLOAD_DIR and UPLOAD_PUBLIC_URL
+fn storage_driver(errors: &mut Vec<String>) -> StorageDriver {
+    let driver = env::var("STORAGE_DRIVER").unwrap_or_else(|_| "local".to_string());
+
+    let upload_dir = env::var("UPLOAD_DIR").unwrap_or_else(|_| "uploads".to_string());
+    if upload_dir.trim().is_empty() {
+        errors.push("UPLOAD_DIR cannot be empty".to_string());
+    }
+
+    let public_url = env::var("UPLOAD_PUBLIC_URL")
+        .unwrap_or_else(|_| "/uploads".to_string())
+        .trim_end_matches('/')
+        .to_string();
+    if !(public_url.starts_with('/') || public_url.starts_with("http://") || public_url.starts_with("https://")) {
+        errors.push("UPLOAD_PUBLIC_URL must be a path like /uploads or a full http(s) URL".to_string());
+    }
+
+    if driver.trim() != "local" {
+        errors.push(format!("STORAGE_DRIVER must be local, got {}", driver.trim()));
+    }
+
+    StorageDriver::Local(LocalStorageConfig {
+        upload_dir: PathBuf::from(upload_dir.trim()),
+        public_url,
+    })
+}
+
+// MAIL_DRIVER is "log" or "smtp", smtp needs SMTP_HOST and optionally SMTP_PORT, SMTP_USERNAME and SMTP_PASSWORD
+fn mail_driver(errors: &mut Vec<String>) -> MailDriver {
+    let driver = env::var("MAIL_DRIVER").unwrap_or_else(|_| "log".to_string());
+
+    match driver.trim() {
+        "log" => MailDriver::Log,
+        "smtp" => {
+            let host = required("SMTP_HOST", errors);
+            let port = parse("SMTP_PORT", 587, errors);
+            let username = env::var("SMTP_USERNAME").ok().filter(|v| !v.is_empty());
+            let password = env::var("SMTP_PASSWORD").ok().filter(|v| !v.is_empty());
+
+            if username.is_some() != password.is_some() {
+                errors.push("SMTP_USERNAME and SMTP_PASSWORD must be set together".to_string());
+            }
+
+            MailDriver::Smtp(SmtpConfig { host, port, username, password })
+        },
+        other => {
+            errors.push(format!("MAIL_DRIVER must be log or smtp, got {}", other));
+            MailDriver::Log
+        }
+    }
+}
@@ -1,15 +1,16 @@
 use sqlx::mysql::{MySqlPool, MySqlPoolOptions};
 
-pub async fn connect() -> MySqlPool {
+// Import app config
+use crate::config::AppConfig;
 
-    // Take DATABASE_URL from environment variable
-    let database_url = std::env::var("DATABASE_URL")
-        .expect("DATABASE_URL not found");
+pub async fn connect(config: &AppConfig) -> MySqlPool {
 
     // Try to connect to database
     match MySqlPoolOptions::new()
-        .max_connections(10)
-        .connect(&database_url)
+        .max_connections(config.db_max_connections)
+        .min_connections(config.db_min_connections)
+        .acquire_timeout(config.db_acquire_timeout)
+        .connect(&config.database_url)
         .await
     {
         Ok(pool) => {
@@ -21,4 +22,4 @@ pub async fn connect() -> MySqlPool {
             std::process::exit(1);
         }
     }
-}
\ No newline at end of file
+}
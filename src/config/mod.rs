@@ -1 +1,4 @@
-pub mod database;
\ No newline at end of file
+pub mod database;
+pub mod app_config;
+
+pub use app_config::AppConfig;
\ No newline at end of file
@@ -0,0 +1,90 @@
+use axum::{
+    Extension,
+    Json,
+    http::StatusCode,
+    extract::Query,
+};
+
+use sqlx::MySqlPool;
+use serde_json::{
+    json,
+    Value,
+};
+
+use uuid::Uuid;
+use validator::Validate;
+
+// Import audit log model
+use crate::models::audit_log::AuditLog;
+
+// Import audit log schema
+use crate::schemas::audit_log_schema::{
+    AuditLogQuery,
+    DEFAULT_AUDIT_PER_PAGE,
+};
+
+// Import API Response
+use crate::utils::response::ApiResponse;
+
+// Import app error
+use crate::utils::error::ApiResult;
+
+// Handler to get the audit logs, newest first
+pub async fn get_audit_logs(
+    Extension(db): Extension<MySqlPool>,
+    Query(query): Query<AuditLogQuery>,
+) -> ApiResult {
+    // Validate the request
+    query.validate()?;
+
+    let page = query.page.unwrap_or(1);
+    let per_page = query.per_page.unwrap_or(DEFAULT_AUDIT_PER_PAGE);
+    let offset = u64::from(page - 1) * u64::from(per_page);
+
+    let logs = sqlx::query_as!(
+        AuditLog,
+        r#"
+        SELECT
+            a.id AS "id: Uuid",
+            a.actor_id AS "actor_id: Uuid",
+            u.name AS "actor_name?",
+            a.action,
+            a.target_type,
+            a.target_id AS "target_id: Uuid",
+            a.details AS "details: Value",
+            a.created_at
+        FROM Audit_Logs a
+        LEFT JOIN Users u ON u.id = a.actor_id
+        WHERE (? IS NULL OR a.actor_id = ?)
+            AND (? IS NULL OR a.target_type = ?)
+            AND (? IS NULL OR a.target_id = ?)
+            AND (? IS NULL OR a.action = ?)
+        ORDER BY a.created_at DESC
+        LIMIT ? OFFSET ?
+        "#,
+        query.actor_id,
+        query.actor_id,
+        query.target_type,
+        query.target_type,
+        query.target_id,
+        query.target_id,
+        query.action,
+        query.action,
+        per_page,
+        offset
+    )
+    .fetch_all(&db)
+    .await?;
+
+    Ok((
+        // Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "Audit Logs",
+            json!({
+                "page": page,
+                "per_page": per_page,
+                "logs": logs,
+            })))
+    ))
+}
@@ -0,0 +1,602 @@
+use axum::{
+    Extension,
+    Json,
+    http::StatusCode,
+    extract::Path,
+};
+
+use sqlx::MySqlPool;
+use serde_json::json;
+
+use chrono::{NaiveDateTime, Utc};
+use uuid::Uuid;
+
+// Import claims from utils
+use crate::utils::jwt::Claims;
+
+// Import room model
+use crate::models::room::RoomPath;
+
+// Import booking model
+use crate::models::booking::{
+    Booking,
+    BookingPath,
+};
+
+// Import booking schema
+use crate::schemas::booking_schema::{
+    BookingNewRequest,
+    BookingNewResponse,
+    BookingUpdateRequest,
+    BookingUpdateResponse,
+    PaymentStatus,
+};
+
+// Import room schema
+use crate::schemas::room_schema::RoomStatus;
+
+// Import API Response
+use crate::utils::response::ApiResponse;
+
+// Import app error
+use crate::utils::error::{
+    ApiResult,
+    AppError,
+    DbResultExt,
+};
+
+// Import validated JSON extractor
+use crate::utils::validated_json::ValidatedJson;
+
+// Import booking helpers
+use crate::utils::{
+    guard::room_guard,
+    booking_overlap::{find_overlapping_bookings, overlap_error},
+    room_vacancy::sync_room_vacancy,
+};
+
+// Helper to get a booking of the room by id
+async fn find_booking(
+    db: &MySqlPool,
+    room_id: Uuid,
+    booking_id: Uuid,
+) -> Result<Booking, sqlx::Error> {
+    sqlx::query_as!(
+        Booking,
+        r#"
+        SELECT
+            id AS "id: Uuid",
+            room_id AS "room_id: Uuid",
+            user_id AS "user_id: Uuid",
+            check_in,
+            check_out,
+            monthly_rent,
+            payment_status AS "payment_status: PaymentStatus",
+            created_at,
+            updated_at
+        FROM Bookings
+        WHERE id = ? AND room_id = ?
+        "#,
+        booking_id,
+        room_id
+    )
+    .fetch_one(db)
+    .await
+}
+
+// Helper to update the dates and rent of a booking and sync the room vacancy in one transaction,
+// returns the conflicting booking ids without updating when the new dates overlap
+async fn update_booking_tx(
+    db: &MySqlPool,
+    room_id: Uuid,
+    booking_id: Uuid,
+    payload: &BookingUpdateRequest,
+) -> Result<Vec<Uuid>, sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    // Lock the room while the booking dates are checked
+    sqlx::query!(
+        "SELECT id FROM Rooms WHERE id = ? FOR UPDATE",
+        room_id
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
+    let payment_status = sqlx::query_scalar!(
+        r#"SELECT payment_status AS "payment_status: PaymentStatus" FROM Bookings WHERE id = ? FOR UPDATE"#,
+        booking_id
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
+    // A cancelled booking does not hold the room, so its dates cannot overlap
+    if payment_status != PaymentStatus::CANCELLED {
+        let conflicts = find_overlapping_bookings(
+            &mut *tx,
+            room_id,
+            payload.check_in,
+            payload.check_out,
+            Some(booking_id),
+        )
+        .await?;
+
+        if !conflicts.is_empty() {
+            return Ok(conflicts);
+        }
+    }
+
+    sqlx::query!(
+        "
+        UPDATE Bookings
+        SET check_in = ?, check_out = ?, monthly_rent = ?
+        WHERE id = ?
+        ",
+        payload.check_in,
+        payload.check_out,
+        payload.monthly_rent,
+        booking_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sync_room_vacancy(&mut *tx, room_id).await?;
+
+    tx.commit().await?;
+
+    Ok(Vec::new())
+}
+
+// Result of deleting a booking, HasInvoices leaves the booking untouched
+enum DeleteBookingOutcome {
+    Deleted,
+    HasInvoices,
+}
+
+// Helper to delete a booking and sync the room vacancy in one transaction.
+// Bookings with invoices are kept, the invoices and their payments are the billing history
+async fn delete_booking_tx(
+    db: &MySqlPool,
+    room_id: Uuid,
+    booking_id: Uuid,
+) -> Result<DeleteBookingOutcome, sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    sqlx::query!(
+        "SELECT id FROM Bookings WHERE id = ? FOR UPDATE",
+        booking_id
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
+    let invoice_count: i64 = sqlx::query_scalar!(
+        r#"SELECT COUNT(*) AS "count!: i64" FROM Invoices WHERE booking_id = ?"#,
+        booking_id
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
+    if invoice_count > 0 {
+        return Ok(DeleteBookingOutcome::HasInvoices);
+    }
+
+    sqlx::query!(
+        "DELETE FROM Bookings WHERE id = ?",
+        booking_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sync_room_vacancy(&mut *tx, room_id).await?;
+
+    tx.commit().await?;
+
+    Ok(DeleteBookingOutcome::Deleted)
+}
+
+// Helper to close a booking (check out or cancel) and sync the room vacancy in one transaction.
+// Cancelling also cancels the unpaid invoices of the booking
+async fn close_booking_tx(
+    db: &MySqlPool,
+    room_id: Uuid,
+    booking_id: Uuid,
+    check_out: Option<NaiveDateTime>,
+    payment_status: PaymentStatus,
+) -> Result<(), sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    sqlx::query!(
+        "
+        UPDATE Bookings
+        SET check_out = ?, payment_status = ?
+        WHERE id = ?
+        ",
+        check_out,
+        payment_status,
+        booking_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    // A cancelled stay is not billed anymore, paid invoices stay as they are
+    if payment_status == PaymentStatus::CANCELLED {
+        sqlx::query!(
+            "
+            UPDATE Invoices
+            SET status = 'CANCELLED'
+            WHERE booking_id = ? AND status IN ('PENDING', 'OVERDUE')
+            ",
+            booking_id
+        )
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    sync_room_vacancy(&mut *tx, room_id).await?;
+
+    tx.commit().await
+}
+
+// Helper to get a booking of the room for a handler, a missing booking is a 404
+async fn find_room_booking(
+    db: &MySqlPool,
+    room_id: Uuid,
+    booking_id: Uuid,
+) -> Result<Booking, AppError> {
+    find_booking(db, room_id, booking_id)
+        .await
+        .or_not_found("Booking with provided id is not found")
+}
+
+// Handler to create new booking
+pub async fn create_booking(
+    Extension(db): Extension<MySqlPool>,
+    Extension(claims): Extension<Claims>,
+    Path(path): Path<RoomPath>,
+    ValidatedJson(payload): ValidatedJson<BookingNewRequest>,
+) -> ApiResult {
+    let (kost_id, room_id) = (path.kost_id, path.room_id);
+
+    // Guard, so only kost owner can record bookings
+    room_guard(&db, &claims, kost_id, room_id).await?;
+
+    // Check the tenant exist
+    sqlx::query!(
+        "SELECT id FROM Users WHERE id = ?",
+        payload.user_id
+    )
+    .fetch_one(&db)
+    .await
+    .or_not_found("User with provided id is not found")?;
+
+    // Start transaction, so the booking and the room vacancy change together
+    let mut tx = db.begin().await?;
+
+    // Lock the room while the booking is recorded
+    let room = sqlx::query!(
+        r#"
+        SELECT room_vacancy AS "room_vacancy: RoomStatus", monthly_rent
+        FROM Rooms
+        WHERE id = ?
+        FOR UPDATE
+        "#,
+        room_id
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
+    if matches!(room.room_vacancy, RoomStatus::MAINTENANCE) {
+        return Err(AppError::Conflict("Room is under maintenance".to_string()));
+    }
+
+    // Use the room price when the agreed rent is not provided
+    let monthly_rent = payload.monthly_rent.unwrap_or(room.monthly_rent);
+
+    if monthly_rent == 0 {
+        return Err(AppError::field("monthly_rent", "Room has no price, monthly rent cannot be empty"));
+    }
+
+    // Insert new booking to database
+    let booking_id = Uuid::new_v4();
+    let payment_status = payload.payment_status.unwrap_or(PaymentStatus::PENDING);
+
+    // Reject the booking when the stay overlaps another booking of the room
+    if payment_status != PaymentStatus::CANCELLED {
+        let conflicts = find_overlapping_bookings(&mut *tx, room_id, payload.check_in, payload.check_out, None).await?;
+
+        if !conflicts.is_empty() {
+            return Err(overlap_error(conflicts));
+        }
+    }
+
+    sqlx::query!(
+        "INSERT INTO Bookings (id, room_id, user_id, check_in, check_out, monthly_rent, payment_status) VALUES (?, ?, ?, ?, ?, ?, ?)",
+        booking_id,
+        room_id,
+        payload.user_id,
+        payload.check_in,
+        payload.check_out,
+        monthly_rent,
+        payment_status
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    // Room becomes OCCUPIED when the new booking is open
+    sync_room_vacancy(&mut *tx, room_id).await?;
+
+    tx.commit().await?;
+
+    // Get newly created booking
+    let booking = find_booking(&db, room_id, booking_id).await?;
+
+    let response = BookingNewResponse {
+        id: booking.id,
+        room_id: booking.room_id,
+        user_id: booking.user_id,
+        check_in: booking.check_in,
+        check_out: booking.check_out,
+        monthly_rent: booking.monthly_rent,
+        payment_status: booking.payment_status,
+        created_at: booking.created_at,
+        updated_at: booking.updated_at,
+    };
+
+    Ok((
+        // Send 201 response Created
+        StatusCode::CREATED,
+        Json(ApiResponse::success(
+            "Booking created successfully",
+            json!(response)))
+    ))
+}
+
+// Handler to get all bookings of a room
+pub async fn get_all_bookings(
+    Extension(db): Extension<MySqlPool>,
+    Extension(claims): Extension<Claims>,
+    Path(path): Path<RoomPath>,
+) -> ApiResult {
+    let (kost_id, room_id) = (path.kost_id, path.room_id);
+
+    // Guard
+    room_guard(&db, &claims, kost_id, room_id).await?;
+
+    let bookings = sqlx::query_as!(
+        Booking,
+        r#"
+        SELECT
+            id AS "id: Uuid",
+            room_id AS "room_id: Uuid",
+            user_id AS "user_id: Uuid",
+            check_in,
+            check_out,
+            monthly_rent,
+            payment_status AS "payment_status: PaymentStatus",
+            created_at,
+            updated_at
+        FROM Bookings
+        WHERE room_id = ?
+        ORDER BY check_in DESC
+        "#,
+        room_id
+    )
+    .fetch_all(&db)
+    .await?;
+
+    Ok((
+        // Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "Bookings List",
+            json!(bookings)))
+    ))
+}
+
+// Handler to get booking by id
+pub async fn get_booking_by_id(
+    Extension(db): Extension<MySqlPool>,
+    Extension(claims): Extension<Claims>,
+    Path(path): Path<BookingPath>,
+) -> ApiResult {
+    // Guard
+    room_guard(&db, &claims, path.kost_id, path.room_id).await?;
+
+    let booking = find_room_booking(&db, path.room_id, path.booking_id).await?;
+
+    let response = BookingNewResponse {
+        id: booking.id,
+        room_id: booking.room_id,
+        user_id: booking.user_id,
+        check_in: booking.check_in,
+        check_out: booking.check_out,
+        monthly_rent: booking.monthly_rent,
+        payment_status: booking.payment_status,
+        created_at: booking.created_at,
+        updated_at: booking.updated_at,
+    };
+
+    Ok((
+        // Send 200 response OK
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "Booking Details",
+            json!(response)))
+    ))
+}
+
+// Handler to update booking
+pub async fn update_booking(
+    Extension(db): Extension<MySqlPool>,
+    Extension(claims): Extension<Claims>,
+    Path(path): Path<BookingPath>,
+    ValidatedJson(payload): ValidatedJson<BookingUpdateRequest>,
+) -> ApiResult {
+    let (room_id, booking_id) = (path.room_id, path.booking_id);
+
+    // Guard
+    room_guard(&db, &claims, path.kost_id, room_id).await?;
+
+    // Check the booking exist
+    find_room_booking(&db, room_id, booking_id).await?;
+
+    // Update booking data and room vacancy in one transaction
+    let conflicts = update_booking_tx(&db, room_id, booking_id, &payload).await?;
+
+    if !conflicts.is_empty() {
+        return Err(overlap_error(conflicts));
+    }
+
+    // Get new booking data
+    let booking = find_booking(&db, room_id, booking_id).await?;
+
+    let response = BookingUpdateResponse {
+        id: booking.id,
+        room_id: booking.room_id,
+        user_id: booking.user_id,
+        check_in: booking.check_in,
+        check_out: booking.check_out,
+        monthly_rent: booking.monthly_rent,
+        payment_status: booking.payment_status,
+        created_at: booking.created_at,
+        updated_at: booking.updated_at,
+    };
+
+    Ok((
+        // Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "Booking updated successfully",
+            json!(response)))
+    ))
+}
+
+// Handler to delete booking
+pub async fn delete_booking(
+    Extension(db): Extension<MySqlPool>,
+    Extension(claims): Extension<Claims>,
+    Path(path): Path<BookingPath>,
+) -> ApiResult {
+    let (room_id, booking_id) = (path.room_id, path.booking_id);
+
+    // Guard
+    room_guard(&db, &claims, path.kost_id, room_id).await?;
+
+    // Check the booking exist
+    let booking = find_room_booking(&db, room_id, booking_id).await?;
+
+    // Delete the booking and release the room in one transaction
+    match delete_booking_tx(&db, room_id, booking.id).await? {
+        DeleteBookingOutcome::Deleted => {},
+        DeleteBookingOutcome::HasInvoices => {
+            return Err(AppError::Conflict(
+                "Booking has invoices, cancel the booking instead of deleting it".to_string()
+            ));
+        },
+    }
+
+    Ok((
+        // Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "Booking deleted successfully",
+            json!(null)))
+    ))
+}
+
+// Handler to check out a booking, the room becomes AVAILABLE when no other booking is open
+pub async fn checkout_booking(
+    Extension(db): Extension<MySqlPool>,
+    Extension(claims): Extension<Claims>,
+    Path(path): Path<BookingPath>,
+) -> ApiResult {
+    let (room_id, booking_id) = (path.room_id, path.booking_id);
+
+    // Guard
+    room_guard(&db, &claims, path.kost_id, room_id).await?;
+
+    // Check the booking exist
+    let booking = find_room_booking(&db, room_id, booking_id).await?;
+
+    let now = Utc::now().naive_utc();
+
+    if booking.payment_status == PaymentStatus::CANCELLED {
+        return Err(AppError::Conflict("Booking has been cancelled".to_string()));
+    }
+
+    if matches!(booking.check_out, Some(check_out) if check_out <= now) {
+        return Err(AppError::Conflict("Booking has already been checked out".to_string()));
+    }
+
+    if booking.check_in >= now {
+        return Err(AppError::Conflict("Booking has not started yet, cancel it instead".to_string()));
+    }
+
+    close_booking_tx(&db, room_id, booking_id, Some(now), booking.payment_status).await?;
+
+    // Get new booking data
+    let booking = find_booking(&db, room_id, booking_id).await?;
+
+    let response = BookingUpdateResponse {
+        id: booking.id,
+        room_id: booking.room_id,
+        user_id: booking.user_id,
+        check_in: booking.check_in,
+        check_out: booking.check_out,
+        monthly_rent: booking.monthly_rent,
+        payment_status: booking.payment_status,
+        created_at: booking.created_at,
+        updated_at: booking.updated_at,
+    };
+
+    Ok((
+        // Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "Booking checked out successfully",
+            json!(response)))
+    ))
+}
+
+// Handler to cancel a booking, the room becomes AVAILABLE when no other booking is open
+pub async fn cancel_booking(
+    Extension(db): Extension<MySqlPool>,
+    Extension(claims): Extension<Claims>,
+    Path(path): Path<BookingPath>,
+) -> ApiResult {
+    let (room_id, booking_id) = (path.room_id, path.booking_id);
+
+    // Guard
+    room_guard(&db, &claims, path.kost_id, room_id).await?;
+
+    // Check the booking exist
+    let booking = find_room_booking(&db, room_id, booking_id).await?;
+
+    if booking.payment_status == PaymentStatus::CANCELLED {
+        return Err(AppError::Conflict("Booking has already been cancelled".to_string()));
+    }
+
+    close_booking_tx(&db, room_id, booking_id, booking.check_out, PaymentStatus::CANCELLED).await?;
+
+    // Get new booking data
+    let booking = find_booking(&db, room_id, booking_id).await?;
+
+    let response = BookingUpdateResponse {
+        id: booking.id,
+        room_id: booking.room_id,
+        user_id: booking.user_id,
+        check_in: booking.check_in,
+        check_out: booking.check_out,
+        monthly_rent: booking.monthly_rent,
+        payment_status: booking.payment_status,
+        created_at: booking.created_at,
+        updated_at: booking.updated_at,
+    };
+
+    Ok((
+        // Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "Booking cancelled successfully",
+            json!(response)))
+    ))
+}
\ No newline at end of file
@@ -0,0 +1,504 @@
+use axum::{
+    Extension,
+    Json,
+    http::StatusCode,
+    extract::{Path, Query},
+};
+
+use sqlx::MySqlPool;
+use serde_json::json;
+
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+
+// Import claims from utils
+use crate::utils::jwt::Claims;
+
+// Import booking request model
+use crate::models::booking_request::{
+    BookingRequest,
+    BookingRequestPath,
+};
+
+// Import booking request schema
+use crate::schemas::booking_request_schema::{
+    BookingRequestNewRequest,
+    BookingRequestRejectRequest,
+    BookingRequestQuery,
+    BookingRequestStatus,
+    BOOKING_REQUEST_TTL_HOURS,
+};
+
+// Import enums
+use crate::schemas::{
+    booking_schema::PaymentStatus,
+    room_schema::RoomStatus,
+};
+
+// Import API Response
+use crate::utils::response::ApiResponse;
+
+// Import app error
+use crate::utils::error::{
+    ApiResult,
+    AppError,
+    DbResultExt,
+};
+
+// Import validated JSON extractor
+use crate::utils::validated_json::ValidatedJson;
+
+// Import booking helpers
+use crate::utils::{
+    guard::kost_guard,
+    booking_overlap::{find_overlapping_bookings, overlap_error},
+    room_vacancy::sync_room_vacancy,
+};
+
+// Result of an approval, everything except Approved leaves the request untouched
+enum ApproveOutcome {
+    Approved,
+    NotRequested,
+    Expired,
+    Maintenance,
+    NoPrice,
+    Conflicts(Vec<Uuid>),
+}
+
+// Helper to get a booking request by id
+async fn find_booking_request(
+    db: &MySqlPool,
+    request_id: Uuid,
+) -> Result<BookingRequest, sqlx::Error> {
+    sqlx::query_as!(
+        BookingRequest,
+        r#"
+        SELECT
+            br.id AS "id: Uuid",
+            r.kost_id AS "kost_id: Uuid",
+            br.room_id AS "room_id: Uuid",
+            r.room_number AS "room_number: u32",
+            br.user_id AS "user_id: Uuid",
+            br.check_in,
+            br.check_out,
+            br.message,
+            br.status AS "status: BookingRequestStatus",
+            br.rejection_reason,
+            br.booking_id AS "booking_id: Uuid",
+            br.decided_by AS "decided_by: Uuid",
+            br.decided_at,
+            br.expires_at,
+            br.created_at,
+            br.updated_at
+        FROM Booking_Requests br
+        JOIN Rooms r ON r.id = br.room_id
+        WHERE br.id = ?
+        "#,
+        request_id
+    )
+    .fetch_one(db)
+    .await
+}
+
+// Helper to approve a request, the booking is created, the room vacancy synced
+// and the request marked as APPROVED in one transaction
+async fn approve_booking_request_tx(
+    db: &MySqlPool,
+    request_id: Uuid,
+    decided_by: Uuid,
+) -> Result<ApproveOutcome, sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    let request = sqlx::query!(
+        r#"
+        SELECT
+            room_id AS "room_id: Uuid",
+            user_id AS "user_id: Uuid",
+            check_in,
+            check_out,
+            status AS "status: BookingRequestStatus",
+            expires_at
+        FROM Booking_Requests
+        WHERE id = ?
+        FOR UPDATE
+        "#,
+        request_id
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
+    if request.status != BookingRequestStatus::REQUESTED {
+        return Ok(ApproveOutcome::NotRequested);
+    }
+
+    // The job may not have run yet, so expire the request here
+    if request.expires_at <= Utc::now().naive_utc() {
+        sqlx::query!(
+            "UPDATE Booking_Requests SET status = 'EXPIRED' WHERE id = ?",
+            request_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        return Ok(ApproveOutcome::Expired);
+    }
+
+    // Lock the room while the booking is recorded
+    let room = sqlx::query!(
+        r#"
+        SELECT room_vacancy AS "room_vacancy: RoomStatus", monthly_rent
+        FROM Rooms
+        WHERE id = ?
+        FOR UPDATE
+        "#,
+        request.room_id
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
+    if matches!(room.room_vacancy, RoomStatus::MAINTENANCE) {
+        return Ok(ApproveOutcome::Maintenance);
+    }
+
+    // The booking takes the room price, a room without price would only get invoices that cannot be paid
+    if room.monthly_rent == 0 {
+        return Ok(ApproveOutcome::NoPrice);
+    }
+
+    let conflicts = find_overlapping_bookings(
+        &mut *tx,
+        request.room_id,
+        request.check_in,
+        request.check_out,
+        None,
+    )
+    .await?;
+
+    if !conflicts.is_empty() {
+        return Ok(ApproveOutcome::Conflicts(conflicts));
+    }
+
+    let booking_id = Uuid::new_v4();
+
+    sqlx::query!(
+        "INSERT INTO Bookings (id, room_id, user_id, check_in, check_out, monthly_rent, payment_status) VALUES (?, ?, ?, ?, ?, ?, ?)",
+        booking_id,
+        request.room_id,
+        request.user_id,
+        request.check_in,
+        request.check_out,
+        room.monthly_rent,
+        PaymentStatus::PENDING
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sync_room_vacancy(&mut *tx, request.room_id).await?;
+
+    sqlx::query!(
+        r#"
+        UPDATE Booking_Requests
+        SET status = 'APPROVED', booking_id = ?, decided_by = ?, decided_at = CURRENT_TIMESTAMP
+        WHERE id = ?
+        "#,
+        booking_id,
+        decided_by,
+        request_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(ApproveOutcome::Approved)
+}
+
+// Handler for a member to request an available room
+pub async fn create_booking_request(
+    Extension(db): Extension<MySqlPool>,
+    Extension(claims): Extension<Claims>,
+    ValidatedJson(payload): ValidatedJson<BookingRequestNewRequest>,
+) -> ApiResult {
+    // Check the room exist and can be requested
+    let room = sqlx::query!(
+        r#"
+        SELECT room_vacancy AS "room_vacancy: RoomStatus"
+        FROM Rooms
+        WHERE id = ?
+        "#,
+        payload.room_id
+    )
+    .fetch_one(&db)
+    .await
+    .or_not_found("Room with provided id is not found")?;
+
+    if !matches!(room.room_vacancy, RoomStatus::AVAILABLE) {
+        return Err(AppError::Conflict("Only available room can be requested".to_string()));
+    }
+
+    // One open request per member and room
+    let open_request = sqlx::query!(
+        r#"
+        SELECT id AS "id: Uuid"
+        FROM Booking_Requests
+        WHERE room_id = ?
+            AND user_id = ?
+            AND status = 'REQUESTED'
+            AND expires_at > UTC_TIMESTAMP()
+        LIMIT 1
+        "#,
+        payload.room_id,
+        claims.sub
+    )
+    .fetch_optional(&db)
+    .await?;
+
+    if open_request.is_some() {
+        return Err(AppError::Conflict("You already have an open request for this room".to_string()));
+    }
+
+    // Reject the request early when the stay overlaps a booking, the approval checks again
+    let conflicts = find_overlapping_bookings(&db, payload.room_id, payload.check_in, payload.check_out, None).await?;
+
+    if !conflicts.is_empty() {
+        return Err(AppError::Conflict("Room is already booked for the requested dates".to_string()));
+    }
+
+    // Insert new booking request to database
+    let request_id = Uuid::new_v4();
+    let expires_at = Utc::now().naive_utc() + Duration::hours(BOOKING_REQUEST_TTL_HOURS);
+
+    sqlx::query!(
+        "INSERT INTO Booking_Requests (id, room_id, user_id, check_in, check_out, message, expires_at) VALUES (?, ?, ?, ?, ?, ?, ?)",
+        request_id,
+        payload.room_id,
+        claims.sub,
+        payload.check_in,
+        payload.check_out,
+        payload.message,
+        expires_at
+    )
+    .execute(&db)
+    .await?;
+
+    // Get newly created request
+    let request = find_booking_request(&db, request_id).await?;
+
+    Ok((
+        // Send 201 response Created
+        StatusCode::CREATED,
+        Json(ApiResponse::success(
+            "Booking request sent successfully",
+            json!(request)))
+    ))
+}
+
+// Handler to get the booking requests of the current user
+pub async fn get_my_booking_requests(
+    Extension(db): Extension<MySqlPool>,
+    Extension(claims): Extension<Claims>,
+    Query(query): Query<BookingRequestQuery>,
+) -> ApiResult {
+    let requests = sqlx::query_as!(
+        BookingRequest,
+        r#"
+        SELECT
+            br.id AS "id: Uuid",
+            r.kost_id AS "kost_id: Uuid",
+            br.room_id AS "room_id: Uuid",
+            r.room_number AS "room_number: u32",
+            br.user_id AS "user_id: Uuid",
+            br.check_in,
+            br.check_out,
+            br.message,
+            br.status AS "status: BookingRequestStatus",
+            br.rejection_reason,
+            br.booking_id AS "booking_id: Uuid",
+            br.decided_by AS "decided_by: Uuid",
+            br.decided_at,
+            br.expires_at,
+            br.created_at,
+            br.updated_at
+        FROM Booking_Requests br
+        JOIN Rooms r ON r.id = br.room_id
+        WHERE br.user_id = ?
+            AND (? IS NULL OR br.status = ?)
+        ORDER BY br.created_at DESC
+        "#,
+        claims.sub,
+        query.status,
+        query.status
+    )
+    .fetch_all(&db)
+    .await?;
+
+    Ok((
+        // Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "My Booking Requests",
+            json!(requests)))
+    ))
+}
+
+// Handler to get the booking requests of a kost
+pub async fn get_kost_booking_requests(
+    Extension(db): Extension<MySqlPool>,
+    Extension(claims): Extension<Claims>,
+    Path(kost_id): Path<Uuid>,
+    Query(query): Query<BookingRequestQuery>,
+) -> ApiResult {
+    // Guard
+    kost_guard(&db, &claims, kost_id).await?;
+
+    let requests = sqlx::query_as!(
+        BookingRequest,
+        r#"
+        SELECT
+            br.id AS "id: Uuid",
+            r.kost_id AS "kost_id: Uuid",
+            br.room_id AS "room_id: Uuid",
+            r.room_number AS "room_number: u32",
+            br.user_id AS "user_id: Uuid",
+            br.check_in,
+            br.check_out,
+            br.message,
+            br.status AS "status: BookingRequestStatus",
+            br.rejection_reason,
+            br.booking_id AS "booking_id: Uuid",
+            br.decided_by AS "decided_by: Uuid",
+            br.decided_at,
+            br.expires_at,
+            br.created_at,
+            br.updated_at
+        FROM Booking_Requests br
+        JOIN Rooms r ON r.id = br.room_id
+        WHERE r.kost_id = ?
+            AND (? IS NULL OR br.status = ?)
+        ORDER BY br.created_at DESC
+        "#,
+        kost_id,
+        query.status,
+        query.status
+    )
+    .fetch_all(&db)
+    .await?;
+
+    Ok((
+        // Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "Booking Requests List",
+            json!(requests)))
+    ))
+}
+
+// Helper to get a request of the kost, a request of another kost is reported as not found
+async fn find_kost_booking_request(
+    db: &MySqlPool,
+    kost_id: Uuid,
+    request_id: Uuid,
+) -> Result<BookingRequest, AppError> {
+    let request = find_booking_request(db, request_id)
+        .await
+        .or_not_found("Booking request with provided id is not found")?;
+
+    if request.kost_id != kost_id {
+        return Err(AppError::NotFound("Booking request with provided id is not found".to_string()));
+    }
+
+    Ok(request)
+}
+
+// Handler for the owner to approve a request, which creates the booking
+pub async fn approve_booking_request(
+    Extension(db): Extension<MySqlPool>,
+    Extension(claims): Extension<Claims>,
+    Path(path): Path<BookingRequestPath>,
+) -> ApiResult {
+    // Guard, so only kost owner can approve requests
+    kost_guard(&db, &claims, path.kost_id).await?;
+
+    // Check the request belongs to the kost
+    find_kost_booking_request(&db, path.kost_id, path.request_id).await?;
+
+    match approve_booking_request_tx(&db, path.request_id, claims.sub).await? {
+        ApproveOutcome::Approved => {},
+        ApproveOutcome::NotRequested => {
+            return Err(AppError::Conflict("Booking request has already been decided".to_string()));
+        },
+        ApproveOutcome::Expired => {
+            return Err(AppError::Conflict("Booking request has expired".to_string()));
+        },
+        ApproveOutcome::Maintenance => {
+            return Err(AppError::Conflict("Room is under maintenance".to_string()));
+        },
+        ApproveOutcome::NoPrice => {
+            return Err(AppError::Conflict(
+                "Room has no price, set the monthly rent of the room first".to_string()
+            ));
+        },
+        ApproveOutcome::Conflicts(conflicts) => {
+            return Err(overlap_error(conflicts));
+        },
+    }
+
+    // Get new request data, it now points to the booking
+    let request = find_booking_request(&db, path.request_id).await?;
+
+    Ok((
+        // Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "Booking request approved successfully",
+            json!(request)))
+    ))
+}
+
+// Handler for the owner to reject a request
+pub async fn reject_booking_request(
+    Extension(db): Extension<MySqlPool>,
+    Extension(claims): Extension<Claims>,
+    Path(path): Path<BookingRequestPath>,
+    ValidatedJson(payload): ValidatedJson<BookingRequestRejectRequest>,
+) -> ApiResult {
+    // Guard
+    kost_guard(&db, &claims, path.kost_id).await?;
+
+    let request = find_kost_booking_request(&db, path.kost_id, path.request_id).await?;
+
+    if request.status != BookingRequestStatus::REQUESTED {
+        return Err(AppError::Conflict("Booking request has already been decided".to_string()));
+    }
+
+    // The status check in the query keeps a concurrent approval from being overwritten
+    let result = sqlx::query!(
+        r#"
+        UPDATE Booking_Requests
+        SET status = 'REJECTED', rejection_reason = ?, decided_by = ?, decided_at = CURRENT_TIMESTAMP
+        WHERE id = ? AND status = 'REQUESTED'
+        "#,
+        payload.reason,
+        claims.sub,
+        request.id
+    )
+    .execute(&db)
+    .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(AppError::Conflict("Booking request has already been decided".to_string()));
+    }
+
+    // Get new request data
+    let request = find_booking_request(&db, request.id).await?;
+
+    Ok((
+        // Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "Booking request rejected successfully",
+            json!(request)))
+    ))
+}
\ No newline at end of file
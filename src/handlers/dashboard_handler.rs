@@ -0,0 +1,130 @@
+use std::collections::HashMap;
+
+use axum::{
+    Extension,
+    Json,
+    http::StatusCode,
+};
+
+use sqlx::MySqlPool;
+use serde_json::json;
+
+use uuid::Uuid;
+
+// Import claims from utils
+use crate::utils::jwt::Claims;
+
+// Import dashboard schema
+use crate::schemas::dashboard_schema::{
+    DashboardResponse,
+    KostSummary,
+    RoomCounts,
+};
+
+// Import API Response
+use crate::utils::response::ApiResponse;
+
+// Import app error
+use crate::utils::error::ApiResult;
+
+// Import invoice helper
+use crate::utils::invoice::current_billing_period;
+
+// Handler to get the summary of every kost owned by the current user
+pub async fn get_dashboard(
+    Extension(db): Extension<MySqlPool>,
+    Extension(claims): Extension<Claims>,
+) -> ApiResult {
+    let period_start = current_billing_period();
+
+    // Room counts by vacancy per kost
+    let rooms = sqlx::query!(
+        r#"
+        SELECT
+            k.id AS "id: Uuid",
+            k.kost_name,
+            COUNT(r.id) AS "total!: i64",
+            CAST(COALESCE(SUM(r.room_vacancy = 'AVAILABLE'), 0) AS SIGNED) AS "available!: i64",
+            CAST(COALESCE(SUM(r.room_vacancy = 'OCCUPIED'), 0) AS SIGNED) AS "occupied!: i64",
+            CAST(COALESCE(SUM(r.room_vacancy = 'MAINTENANCE'), 0) AS SIGNED) AS "maintenance!: i64"
+        FROM Kosts k
+        LEFT JOIN Rooms r ON r.kost_id = k.id
+        WHERE k.user_id = ?
+        GROUP BY k.id, k.kost_name
+        ORDER BY k.kost_name ASC
+        "#,
+        claims.sub
+    )
+    .fetch_all(&db)
+    .await?;
+
+    // Overdue invoices and this month's rent per kost
+    let invoices = sqlx::query!(
+        r#"
+        SELECT
+            r.kost_id AS "kost_id: Uuid",
+            CAST(COALESCE(SUM(i.status = 'OVERDUE'), 0) AS SIGNED) AS "overdue!: i64",
+            CAST(COALESCE(SUM(CASE WHEN i.period_start = ? AND i.status != 'CANCELLED' THEN i.amount END), 0) AS UNSIGNED) AS "expected!: u64",
+            CAST(COALESCE(SUM(CASE WHEN i.period_start = ? AND i.status != 'CANCELLED' THEN i.paid_amount END), 0) AS UNSIGNED) AS "collected!: u64"
+        FROM Invoices i
+        JOIN Bookings b ON b.id = i.booking_id
+        JOIN Rooms r ON r.id = b.room_id
+        JOIN Kosts k ON k.id = r.kost_id
+        WHERE k.user_id = ?
+        GROUP BY r.kost_id
+        "#,
+        period_start,
+        period_start,
+        claims.sub
+    )
+    .fetch_all(&db)
+    .await?
+    .into_iter()
+    .map(|i| (i.kost_id, (i.overdue, i.expected, i.collected)))
+    .collect::<HashMap<Uuid, (i64, u64, u64)>>();
+
+    let kosts = rooms
+        .into_iter()
+        .map(|kost| {
+            let (overdue_invoices, expected_rent, collected_rent) = invoices
+                .get(&kost.id)
+                .copied()
+                .unwrap_or((0, 0, 0));
+
+            let rentable = kost.total - kost.maintenance;
+            let occupancy_rate = if rentable > 0 {
+                (kost.occupied as f64 / rentable as f64 * 10000.0).round() / 100.0
+            } else {
+                0.0
+            };
+
+            KostSummary {
+                kost_id: kost.id,
+                kost_name: kost.kost_name,
+                rooms: RoomCounts {
+                    total: kost.total,
+                    available: kost.available,
+                    occupied: kost.occupied,
+                    maintenance: kost.maintenance,
+                },
+                occupancy_rate,
+                overdue_invoices,
+                expected_rent,
+                collected_rent,
+            }
+        })
+        .collect::<Vec<KostSummary>>();
+
+    let response = DashboardResponse {
+        period_start,
+        kosts,
+    };
+
+    Ok((
+        // Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "Dashboard Summary",
+            json!(response)))
+    ))
+}
@@ -0,0 +1,181 @@
+use axum::{
+    Extension,
+    Json,
+    http::StatusCode,
+    extract::Path,
+};
+
+use sqlx::MySqlPool;
+use serde_json::json;
+
+use uuid::Uuid;
+
+// Import email verification schema
+use crate::schemas::email_verification_schema::VerifyEmailRequest;
+
+// Import API Response
+use crate::utils::response::ApiResponse;
+
+// Import app error
+use crate::utils::error::{
+    ApiResult,
+    AppError,
+    DbResultExt,
+};
+
+// Import validated JSON extractor
+use crate::utils::validated_json::ValidatedJson;
+
+// Import claims from utils
+use crate::utils::jwt::Claims;
+
+// Import mailer
+use crate::mailer::SharedMailer;
+
+// Import helpers
+use crate::utils::{
+    audit::record_audit,
+    email_verification::{issue_verification_token, send_verification_email},
+    secure_token::hash_token,
+};
+
+// Helper to mark the email of the token owner as verified in one transaction, returns false when the token is not valid
+async fn verify_email_tx(
+    db: &MySqlPool,
+    token: &str,
+) -> Result<bool, sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    let verification = match sqlx::query!(
+        r#"
+        SELECT id AS "id: Uuid", user_id AS "user_id: Uuid"
+        FROM Email_Verifications
+        WHERE token_hash = ? AND used_at IS NULL AND expires_at > UTC_TIMESTAMP()
+        FOR UPDATE
+        "#,
+        hash_token(token)
+    )
+    .fetch_optional(&mut *tx)
+    .await?
+    {
+        Some(verification) => verification,
+        None => return Ok(false),
+    };
+
+    sqlx::query!(
+        "UPDATE Users SET email_verified_at = UTC_TIMESTAMP() WHERE id = ? AND email_verified_at IS NULL",
+        verification.user_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    // Older links of the user stop working too
+    sqlx::query!(
+        "UPDATE Email_Verifications SET used_at = UTC_TIMESTAMP() WHERE user_id = ? AND used_at IS NULL",
+        verification.user_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    record_audit(
+        &mut *tx,
+        Some(verification.user_id),
+        "user.email_verified",
+        "user",
+        Some(verification.user_id),
+        json!({ "verification_id": verification.id }),
+    )
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(true)
+}
+
+// Handler to verify an email with the token from the verification link
+pub async fn verify_email(
+    Extension(db): Extension<MySqlPool>,
+    ValidatedJson(payload): ValidatedJson<VerifyEmailRequest>,
+) -> ApiResult {
+    let verified = verify_email_tx(&db, &payload.token).await?;
+
+    if !verified {
+        return Err(AppError::BadRequest("Verification token is not valid or has expired".to_string()));
+    }
+
+    Ok((
+        // Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "Email verified successfully, you can login now",
+            json!(null)))
+    ))
+}
+
+// Helper to replace the pending verification tokens of the user with a new one, returns the token to email
+async fn resend_verification_tx(
+    db: &MySqlPool,
+    actor_id: Uuid,
+    user_id: Uuid,
+) -> Result<String, sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    sqlx::query!(
+        "DELETE FROM Email_Verifications WHERE user_id = ? AND used_at IS NULL",
+        user_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    let token = issue_verification_token(&mut *tx, user_id).await?;
+
+    record_audit(
+        &mut *tx,
+        Some(actor_id),
+        "user.verification_resent",
+        "user",
+        Some(user_id),
+        json!({}),
+    )
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(token)
+}
+
+// Handler for admins to send a new verification link, e.g. when the first one expired or got lost
+pub async fn resend_verification(
+    Extension(db): Extension<MySqlPool>,
+    Extension(claims): Extension<Claims>,
+    Extension(mailer): Extension<SharedMailer>,
+    Path(user_id): Path<Uuid>,
+) -> ApiResult {
+    let user = sqlx::query!(
+        r#"
+        SELECT id AS "id: Uuid", name, email, email_verified_at
+        FROM Users
+        WHERE id = ?
+        "#,
+        user_id
+    )
+    .fetch_one(&db)
+    .await
+    .or_not_found("User with provided id is not found")?;
+
+    if user.email_verified_at.is_some() {
+        return Err(AppError::Conflict("Email is already verified".to_string()));
+    }
+
+    let token = resend_verification_tx(&db, claims.sub, user.id).await?;
+
+    send_verification_email(mailer, &user.name, &user.email, &token);
+
+    Ok((
+        // Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "Verification email has been sent",
+            json!(null)))
+    ))
+}
@@ -0,0 +1,223 @@
+use axum::{
+    Extension,
+    Json,
+    http::StatusCode,
+    extract::{Path, Query},
+};
+
+use sqlx::MySqlPool;
+use serde_json::json;
+
+use uuid::Uuid;
+
+// Import claims from utils
+use crate::utils::jwt::Claims;
+
+// Import invoice model
+use crate::models::invoice::{
+    Invoice,
+    InvoicePath,
+};
+
+// Import invoice schema
+use crate::schemas::invoice_schema::{
+    InvoiceGenerateRequest,
+    InvoiceGenerateResponse,
+    InvoiceQuery,
+    parse_period,
+};
+
+// Import payment status enum
+use crate::schemas::booking_schema::PaymentStatus;
+
+// Import API Response
+use crate::utils::response::ApiResponse;
+
+// Import app error
+use crate::utils::error::{
+    ApiResult,
+    AppError,
+    DbResultExt,
+};
+
+// Import validated JSON extractor
+use crate::utils::validated_json::ValidatedJson;
+
+// Import ownership guard
+use crate::utils::guard::kost_guard;
+
+// Import invoice helpers
+use crate::utils::invoice::{
+    current_billing_period,
+    find_invoice,
+    generate_invoices,
+    sync_booking_payment_status,
+};
+
+// Helper to cancel an invoice and sync the booking payment status in one transaction
+async fn cancel_invoice_tx(
+    db: &MySqlPool,
+    invoice_id: Uuid,
+    booking_id: Uuid,
+) -> Result<(), sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    sqlx::query!(
+        "UPDATE Invoices SET status = 'CANCELLED' WHERE id = ?",
+        invoice_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sync_booking_payment_status(&mut *tx, booking_id).await?;
+
+    tx.commit().await
+}
+
+// Handler to generate the monthly invoices of a kost
+pub async fn generate_kost_invoices(
+    Extension(db): Extension<MySqlPool>,
+    Extension(claims): Extension<Claims>,
+    Path(kost_id): Path<Uuid>,
+    payload: Option<ValidatedJson<InvoiceGenerateRequest>>,
+) -> ApiResult {
+    // Guard, so only kost owner can generate invoices
+    kost_guard(&db, &claims, kost_id).await?;
+
+    // The body is optional, no body generates the current month
+    let period_start = payload
+        .and_then(|ValidatedJson(payload)| payload.period)
+        .as_deref()
+        .and_then(parse_period)
+        .unwrap_or_else(current_billing_period);
+
+    let generated = generate_invoices(&db, Some(kost_id), period_start).await?;
+
+    let response = InvoiceGenerateResponse {
+        period_start,
+        generated,
+    };
+
+    Ok((
+        // Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "Invoices generated successfully",
+            json!(response)))
+    ))
+}
+
+// Handler to get all invoices of a kost
+pub async fn get_all_invoices(
+    Extension(db): Extension<MySqlPool>,
+    Extension(claims): Extension<Claims>,
+    Path(kost_id): Path<Uuid>,
+    Query(query): Query<InvoiceQuery>,
+) -> ApiResult {
+    // Guard
+    kost_guard(&db, &claims, kost_id).await?;
+
+    // Billing month filter
+    let period_start = match query.period.as_deref() {
+        Some(period) => Some(
+            parse_period(period)
+                .ok_or_else(|| AppError::field("period", "Period must be in YYYY-MM format"))?
+        ),
+        None => None,
+    };
+
+    let invoices = sqlx::query_as!(
+        Invoice,
+        r#"
+        SELECT
+            i.id AS "id: Uuid",
+            i.booking_id AS "booking_id: Uuid",
+            b.room_id AS "room_id: Uuid",
+            r.room_number AS "room_number: u32",
+            b.user_id AS "user_id: Uuid",
+            i.period_start,
+            i.amount,
+            i.paid_amount,
+            (i.amount - i.paid_amount) AS "outstanding!: u64",
+            i.due_date,
+            i.status AS "status: PaymentStatus",
+            i.created_at,
+            i.updated_at
+        FROM Invoices i
+        JOIN Bookings b ON b.id = i.booking_id
+        JOIN Rooms r ON r.id = b.room_id
+        WHERE r.kost_id = ?
+            AND (? IS NULL OR i.status = ?)
+            AND (? IS NULL OR i.period_start = ?)
+        ORDER BY i.period_start DESC, r.room_number ASC
+        "#,
+        kost_id,
+        query.status,
+        query.status,
+        period_start,
+        period_start
+    )
+    .fetch_all(&db)
+    .await?;
+
+    Ok((
+        // Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "Invoices List",
+            json!(invoices)))
+    ))
+}
+
+// Handler to get invoice by id
+pub async fn get_invoice_by_id(
+    Extension(db): Extension<MySqlPool>,
+    Extension(claims): Extension<Claims>,
+    Path(path): Path<InvoicePath>,
+) -> ApiResult {
+    // Guard
+    kost_guard(&db, &claims, path.kost_id).await?;
+
+    let invoice = find_invoice(&db, path.kost_id, path.invoice_id)
+        .await
+        .or_not_found("Invoice with provided id is not found")?;
+
+    Ok((
+        // Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "Invoice Details",
+            json!(invoice)))
+    ))
+}
+
+// Handler to cancel an unpaid invoice
+pub async fn cancel_invoice(
+    Extension(db): Extension<MySqlPool>,
+    Extension(claims): Extension<Claims>,
+    Path(path): Path<InvoicePath>,
+) -> ApiResult {
+    // Guard
+    kost_guard(&db, &claims, path.kost_id).await?;
+
+    let invoice = find_invoice(&db, path.kost_id, path.invoice_id)
+        .await
+        .or_not_found("Invoice with provided id is not found")?;
+
+    if matches!(invoice.status, PaymentStatus::PAID | PaymentStatus::CANCELLED) {
+        return Err(AppError::Conflict("Only unpaid invoice can be cancelled".to_string()));
+    }
+
+    cancel_invoice_tx(&db, invoice.id, invoice.booking_id).await?;
+
+    // Get new invoice data
+    let invoice = find_invoice(&db, path.kost_id, invoice.id).await?;
+
+    Ok((
+        // Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "Invoice cancelled successfully",
+            json!(invoice)))
+    ))
+}
\ No newline at end of file
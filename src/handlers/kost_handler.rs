@@ -1,5 +1,3 @@
-use std::collections::HashMap;
-
 use axum::{
     Extension,
     Json,
@@ -7,12 +5,8 @@ use axum::{
     extract::Path,
 };
 use sqlx::MySqlPool;
-use serde_json::{
-    json,
-    Value
-};
+use serde_json::json;
 use uuid::Uuid;
-use validator::Validate;
 
 // Import kost models
 use crate::models::kost::Kost;
@@ -23,6 +17,7 @@ use crate::schemas::kost_schema::{
     KostNewResponse,
     KostUpdateRequest,
     KostUpdateResponse,
+    DEFAULT_GRACE_PERIOD_DAYS,
 };
 
 // Import claims from utils
@@ -31,37 +26,25 @@ use crate::utils::jwt::Claims;
 // Import API response form utils
 use crate::utils::response::ApiResponse;
 
+// Import app error
+use crate::utils::error::{
+    ApiResult,
+    AppError,
+    DbResultExt,
+};
+
+// Import validated JSON extractor
+use crate::utils::validated_json::ValidatedJson;
+
+// Import storage
+use crate::storage::SharedStorage;
+
 // Handler to create new kost
 pub async fn create_new_kost(
     Extension(db): Extension<MySqlPool>,
     Extension(claims): Extension<Claims>,
-    Json(payload): Json<KostNewRequest>
-) -> (StatusCode, Json<ApiResponse<Value>>) {
-    // Validate the request
-    if let Err(e) = payload.validate() {
-        let mut field_errors: HashMap<String, Vec<String>> = HashMap::new();
-
-        for (field, errors) in e.field_errors() {
-            let messages = errors
-                .iter()
-                .filter_map(|e| e.message.as_ref())
-                .map(|m| m.to_string())
-                .collect::<Vec<String>>();
-
-            field_errors.insert(field.to_string(), messages);
-        }
-
-        return (
-            //Send 402 response Unprocessable Entity
-            StatusCode::UNPROCESSABLE_ENTITY,
-            Json(ApiResponse {
-                status: false,
-                message: "Failed to validate request".to_string(),
-                data: Some(json!(field_errors))
-            })
-        );
-    }
-
+    ValidatedJson(payload): ValidatedJson<KostNewRequest>
+) -> ApiResult {
     // Insert new kost to database
     let kost_id = Uuid::new_v4();
     let kost_user_id = claims.sub;
@@ -73,133 +56,81 @@ pub async fn create_new_kost(
         payload.kost_name,
     )
     .fetch_optional(&db)
-    .await;
-
-    if let Ok(Some(_)) = kost_name_exist {
-        return (
-            // Send 409 response Conflict
-            StatusCode::CONFLICT,
-            Json(ApiResponse::error(
-                "Kost name already exist"
-            ))
-        );
+    .await?;
+
+    if kost_name_exist.is_some() {
+        return Err(AppError::Conflict("Kost name already exist".to_string()));
     }
 
-    let result = sqlx::query!(
-        "INSERT INTO Kosts (id, user_id, kost_name, kost_address, kost_contact, kost_desc) VALUES (?, ?, ?, ?, ?, ?)",
+    sqlx::query!(
+        "INSERT INTO Kosts (id, user_id, kost_name, kost_address, kost_contact, kost_desc, grace_period_days) VALUES (?, ?, ?, ?, ?, ?, ?)",
         kost_id,
         kost_user_id,
         payload.kost_name,
         payload.kost_address,
         payload.kost_contact,
         payload.kost_desc,
+        payload.grace_period_days.unwrap_or(DEFAULT_GRACE_PERIOD_DAYS),
     )
     .execute(&db)
-    .await;
-
-    match result {
-        Ok(_result) => {
-            // Get newly created kost
-            let kost = sqlx::query_as!(
-                Kost,
-                r#"
-                SELECT id AS "id: Uuid", user_id AS "user_id: Uuid", kost_name, kost_address, kost_contact, kost_desc, created_at, updated_at
-                FROM Kosts
-                WHERE id = ?
-                "#,
-                kost_id
-            )
-            .fetch_one(&db)
-            .await;
-            
-            match kost {
-                Ok(kost) => {
-                    let response = KostNewResponse {
-                        id: kost.id,
-                        user_id: kost.user_id,
-                        kost_name: kost.kost_name,
-                        kost_address: kost.kost_address,
-                        kost_contact: kost.kost_contact,
-                        kost_desc: kost.kost_desc,
-                        created_at: kost.created_at,
-                        updated_at: kost.updated_at,
-                    };
-
-                    (
-                        // Send 201 response Created
-                        StatusCode::CREATED,
-                        Json(ApiResponse::success(
-                            "Kost created successfully", 
-                            json!(response)))
-                    )
-                },
-                Err(_) => (
-                    //Send 500 response Internal Server Error
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    Json(ApiResponse::error(
-                        "Failed to get kost data"
-                    ))
-                )
-            }
-        },
-        Err(e) => {
-            eprintln!("Database error: {}", e);
-            (
-                //Send 500 response Internal Server Error
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ApiResponse::error(
-                    e.to_string().as_ref(),
-                ))
-            )
-        }
-    }
+    .await?;
+
+    // Get newly created kost
+    let kost = sqlx::query_as!(
+        Kost,
+        r#"
+        SELECT id AS "id: Uuid", user_id AS "user_id: Uuid", kost_name, kost_address, kost_contact, kost_desc, grace_period_days, created_at, updated_at
+        FROM Kosts
+        WHERE id = ?
+        "#,
+        kost_id
+    )
+    .fetch_one(&db)
+    .await?;
+
+    let response = KostNewResponse {
+        id: kost.id,
+        user_id: kost.user_id,
+        kost_name: kost.kost_name,
+        kost_address: kost.kost_address,
+        kost_contact: kost.kost_contact,
+        kost_desc: kost.kost_desc,
+        grace_period_days: kost.grace_period_days,
+        created_at: kost.created_at,
+        updated_at: kost.updated_at,
+    };
 
+    Ok((
+        // Send 201 response Created
+        StatusCode::CREATED,
+        Json(ApiResponse::success(
+            "Kost created successfully", 
+            json!(response)))
+    ))
 }
 
 // Handler to get all kost
 pub async fn get_all_kosts(
     Extension(db): Extension<MySqlPool>,
     Extension(claims): Extension<Claims>,
-) -> (StatusCode, Json<ApiResponse<Value>>) {
+) -> ApiResult {
     // Get all kost data
-    if claims.role == "ADMIN" {
-        let kosts = match sqlx::query_as!(
+    let kosts = if claims.role == "ADMIN" {
+        sqlx::query_as!(
             Kost,
             r#"
-            SELECT id AS "id: Uuid", user_id AS "user_id: Uuid", kost_name, kost_address, kost_contact, kost_desc, created_at, updated_at
+            SELECT id AS "id: Uuid", user_id AS "user_id: Uuid", kost_name, kost_address, kost_contact, kost_desc, grace_period_days, created_at, updated_at
             FROM Kosts
             ORDER BY kost_name DESC
             "#,
         )
         .fetch_all(&db)
-        .await
-        {
-            Ok(kosts) => kosts,
-            Err(e) => {
-                eprintln!("Database error: {}", e);
-                return (
-                    // Send 500 response Internal Server Error
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    Json(ApiResponse::error(
-                        e.to_string().as_ref(),
-                    ))
-                );
-            }
-        };
-
-        (
-            // Send 200 response Ok
-            StatusCode::OK,
-            Json(ApiResponse::success(
-                "Kosts List", 
-                json!(kosts),
-            ))
-        )
+        .await?
     } else {
-        let kosts = match sqlx::query_as!(
+        sqlx::query_as!(
             Kost,
             r#"
-            SELECT id AS "id: Uuid", user_id AS "user_id: Uuid", kost_name, kost_address, kost_contact, kost_desc, created_at, updated_at
+            SELECT id AS "id: Uuid", user_id AS "user_id: Uuid", kost_name, kost_address, kost_contact, kost_desc, grace_period_days, created_at, updated_at
             FROM Kosts
             WHERE user_id = ?
             ORDER BY kost_name DESC
@@ -207,30 +138,16 @@ pub async fn get_all_kosts(
             claims.sub
         )
         .fetch_all(&db)
-        .await
-        {
-            Ok(kosts) => kosts,
-            Err(e) => {
-                    eprintln!("Database error: {}", e);
-                    return (
-                        // Send 500 response Internal Server Error
-                        StatusCode::INTERNAL_SERVER_ERROR,
-                        Json(ApiResponse::error(
-                            e.to_string().as_ref(),
-                        ))
-                    );
-            }
-        };
-
-        (
-            // Send 200 response Ok
-            StatusCode::OK,
-            Json(ApiResponse::success(
-                "Kosts List", 
-                json!(kosts)))
-        )
-        
-    }
+        .await?
+    };
+
+    Ok((
+        // Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "Kosts List", 
+            json!(kosts)))
+    ))
 }
 
 // Handler to get kost detail
@@ -238,10 +155,10 @@ pub async fn get_kost_by_id(
     Path(id): Path<Uuid>,
     Extension(claims): Extension<Claims>,
     Extension(db): Extension<MySqlPool>
-) -> (StatusCode, Json<ApiResponse<Value>>) {
+) -> ApiResult {
 
     // Get kost data by kost id
-    let kost = match sqlx::query!(
+    let kost = sqlx::query!(
         r#"
             SELECT 
                 id AS "id: Uuid", 
@@ -250,6 +167,7 @@ pub async fn get_kost_by_id(
                 kost_address,
                 kost_contact,
                 kost_desc,
+                grace_period_days,
                 created_at,
                 updated_at
             FROM Kosts
@@ -259,38 +177,11 @@ pub async fn get_kost_by_id(
     )
     .fetch_one(&db)
     .await
-    {
-        Ok(kost) => kost,
-        Err(sqlx::Error::RowNotFound) => {
-            return (
-                // Send 404 response Not Found
-                StatusCode::NOT_FOUND,
-                Json(ApiResponse::error(
-                    "Kost not found"
-                ))
-            );
-        },
-        Err(e) => {
-            eprintln!("{}", e);
-            return (
-                // Send 500 response Iternal Server Error,
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ApiResponse::error(
-                    "Failed to get kost data"
-                ))
-            );
-        }
-    };
+    .or_not_found("Kost not found")?;
 
     // Guard, only matched user id can access the kost
     if kost.user_id != claims.sub {
-        return (
-            // Send 401 response Unauthorized
-            StatusCode::UNAUTHORIZED,
-            Json(ApiResponse::error(
-                "Only owner can access this kost",
-            ))
-        )
+        return Err(AppError::Unauthorized("Only owner can access this kost".to_string()));
     }
 
     let response = KostNewResponse {
@@ -300,17 +191,18 @@ pub async fn get_kost_by_id(
         kost_address: kost.kost_address,
         kost_contact: kost.kost_contact,
         kost_desc: kost.kost_desc,
+        grace_period_days: kost.grace_period_days,
         created_at: kost.created_at,
         updated_at: kost. updated_at,
     };
 
-    (
+    Ok((
         // Send 200 response Ok
         StatusCode::OK,
         Json(ApiResponse::success(
             "Kost details", 
             json!(response))),
-    )
+    ))
 }
 
 // Handler to update kost data
@@ -318,35 +210,10 @@ pub async fn update_kost(
     Extension(db): Extension<MySqlPool>,
     Extension(claims): Extension<Claims>,
     Path(id): Path<Uuid>,
-    Json(payload): Json<KostUpdateRequest>,
-) -> (StatusCode, Json<ApiResponse<Value>>) {
-    // Validate the request
-    if let Err(e) = payload.validate() {
-        let mut field_errors: HashMap<String, Vec<String>> = HashMap::new();
-
-        for (field, errors) in e.field_errors() {
-            let messages = errors
-                .iter()
-                .filter_map(|e| e.message.as_ref())
-                .map(|m| m.to_string())
-                .collect::<Vec<String>>();
-
-            field_errors.insert(field.to_string(), messages);
-        }
-
-        return (
-            // Send 422 response Unprocessable Entity
-            StatusCode::UNPROCESSABLE_ENTITY,
-            Json(ApiResponse{
-                status: false,
-                message: "Failed to validate".to_string(),
-                data: Some(json!(field_errors))
-            })
-        );
-    }
-
+    ValidatedJson(payload): ValidatedJson<KostUpdateRequest>,
+) -> ApiResult {
     // Check if the kost exist
-    let kost = match sqlx::query!(
+    let kost = sqlx::query!(
         r#"
         SELECT id, user_id AS "user_id: Uuid"
         FROM Kosts
@@ -356,65 +223,29 @@ pub async fn update_kost(
     ) 
     .fetch_one(&db)
     .await
-    {
-        Ok(kost) => kost,
-        Err(sqlx::Error::RowNotFound) => {
-            return (
-                // Send 404 response not found
-                StatusCode::NOT_FOUND,
-                Json(ApiResponse::error(
-                    "Kost with provided id is not found"
-                ))
-            );
-        },
-        Err(_) => {
-            return (
-                // Send 500 response Internal Server Error
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ApiResponse::error(
-                    "Server Error"
-                ))
-            );
-        }
-    };
+    .or_not_found("Kost with provided id is not found")?;
 
     // Guard, only owner can update the kost
     if kost.user_id != claims.sub {
-        return (
-            // Send 401 response Unauthorized
-            StatusCode::UNAUTHORIZED,
-            Json(ApiResponse::error(
-                "Only owner can update the kost",
-            ))
-        );
+        return Err(AppError::Unauthorized("Only owner can update the kost".to_string()));
     }
 
-    // Update kost data
-    let result = sqlx::query!(
+    // Update kost data, a missing grace period keeps the current one
+    sqlx::query!(
         "
         UPDATE Kosts
-        SET kost_name = ?, kost_address = ?, kost_contact = ?, kost_desc = ?
+        SET kost_name = ?, kost_address = ?, kost_contact = ?, kost_desc = ?, grace_period_days = COALESCE(?, grace_period_days)
         WHERE id = ?
         ",
         payload.kost_name,
         payload.kost_address,
         payload.kost_contact,
         payload.kost_desc,
+        payload.grace_period_days,
         id,
     )
     .execute(&db)
-    .await;
-
-    if let Err(e) = result {
-        eprintln!("Database error: {}", e);
-        return (
-            // Send 500 response Internal Server Error
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ApiResponse::error(
-                e.to_string().as_ref(),
-            ))
-        );
-    }
+    .await?;
 
     // Get kost new data
     let updated_kost = sqlx::query!(
@@ -426,6 +257,7 @@ pub async fn update_kost(
             kost_address,
             kost_contact,
             kost_desc,
+            grace_period_days,
             created_at,
             updated_at
         FROM Kosts
@@ -434,39 +266,27 @@ pub async fn update_kost(
         id
     )
     .fetch_one(&db)
-    .await;
-
-    match updated_kost {
-        Ok(updated_kost) => {
-            let response = KostUpdateResponse {
-                id: updated_kost.id,
-                user_id: updated_kost.user_id,
-                kost_name: updated_kost.kost_name,
-                kost_address: updated_kost.kost_address,
-                kost_contact: updated_kost.kost_contact,
-                kost_desc: updated_kost.kost_desc,
-                created_at: updated_kost.created_at,
-                update_at: updated_kost.updated_at,
-            };
-
-            return (
-                // Send 200 response Ok
-                StatusCode::OK,
-                Json(ApiResponse::success(
-                    "Kost updated successfully", 
-                    json!(response))),
-            );
-        },
-        Err(_) => {
-            return (
-                // Send 500 response Internal Server Error
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ApiResponse::error(
-                    "Server Error",
-                ))
-            );
-        }
-    }
+    .await?;
+
+    let response = KostUpdateResponse {
+        id: updated_kost.id,
+        user_id: updated_kost.user_id,
+        kost_name: updated_kost.kost_name,
+        kost_address: updated_kost.kost_address,
+        kost_contact: updated_kost.kost_contact,
+        kost_desc: updated_kost.kost_desc,
+        grace_period_days: updated_kost.grace_period_days,
+        created_at: updated_kost.created_at,
+        update_at: updated_kost.updated_at,
+    };
+
+    Ok((
+        // Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "Kost updated successfully", 
+            json!(response))),
+    ))
 }
 
 // Handler to delete kost
@@ -474,75 +294,50 @@ pub async fn delete_kost(
     Path(id): Path<Uuid>,
     Extension(db): Extension<MySqlPool>,
     Extension(claims): Extension<Claims>,
-) -> (StatusCode, Json<ApiResponse<Value>>) {
+    Extension(storage): Extension<SharedStorage>,
+) -> ApiResult {
     // Check if the kost exist
-    let kost = match sqlx::query!(
+    let kost = sqlx::query!(
         r#"SELECT id, user_id AS "user_id: Uuid" FROM Kosts WHERE id = ?"#,
         id
     )
     .fetch_one(&db)
     .await 
-    {
-        Ok(kost) => kost,
-        Err(sqlx::Error::RowNotFound) => {
-            return (
-                // Send 404 response Not Found
-                StatusCode::NOT_FOUND,
-                Json(ApiResponse::error(
-                    "Kost with provided id is not found",
-                ))
-            );
-        },
-        Err(e) => {
-            eprintln!("Database error: {}", e);
-            return (
-                // Send 500 response Internal Server Error
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ApiResponse::error(
-                    e.to_string().as_ref(),
-                ))
-            );
-        }
-    };
+    .or_not_found("Kost with provided id is not found")?;
 
     // Guard, so only kost owner can delete the kost
     if kost.user_id != claims.sub {
-        return (
-            // Send 401 response Unauthorized
-            StatusCode::UNAUTHORIZED,
-            Json(ApiResponse::error(
-                "Only kost owner can delete this kost"
-            ))
-        );
+        return Err(AppError::Unauthorized("Only kost owner can delete this kost".to_string()));
     }
 
+    // Image rows are removed by the cascade, keep the keys to remove the stored objects
+    let object_keys: Vec<String> = sqlx::query_scalar!(
+        "SELECT object_key FROM Kost_Images WHERE kost_id = ?",
+        id
+    )
+    .fetch_all(&db)
+    .await?;
+
     // Delete the kost
-    let result = sqlx::query!(
+    sqlx::query!(
         "DELETE FROM Kosts WHERE id = ?",
         id
     )
     .execute(&db) 
-    .await;
-
-    match result {
-        Ok(_) => {
-            return (
-                // Send 200 response Ok
-                StatusCode::OK,
-                Json(ApiResponse::success(
-                    "Kost deleted successfully", 
-                    json!(null)))
-            );
-        },
-        Err(e) => {
-            eprintln!("Database error: {}", e);
-            return (
-                // Send 500 response Internal Server Error
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ApiResponse::error(
-                    e.to_string().as_ref(),
-                ))
-            );
+    .await
+    .or_referenced("Kost has bookings with invoices, cancel the bookings instead of deleting the kost")?;
+
+    for object_key in object_keys {
+        if let Err(e) = storage.delete(&object_key).await {
+            eprintln!("Storage error: {}", e);
         }
-    };
+    }
+
+    Ok((
+        // Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "Kost deleted successfully", 
+            json!(null)))
+    ))
 }
\ No newline at end of file
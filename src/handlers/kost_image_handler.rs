@@ -0,0 +1,233 @@
+use axum::{
+    Extension,
+    Json,
+    http::StatusCode,
+    extract::{Multipart, Path},
+};
+
+use sqlx::MySqlPool;
+use serde_json::json;
+
+use uuid::Uuid;
+
+// Import claims from utils
+use crate::utils::jwt::Claims;
+
+// Import kost image model
+use crate::models::kost_image::{
+    KostImage,
+    KostImagePath,
+};
+
+// Import kost image schema
+use crate::schemas::kost_image_schema::{
+    KostImageResponse,
+    MAX_IMAGE_BYTES,
+    detect_image_type,
+};
+
+// Import API Response
+use crate::utils::response::ApiResponse;
+
+// Import app error
+use crate::utils::error::{
+    ApiResult,
+    AppError,
+    DbResultExt,
+};
+
+// Import ownership guard
+use crate::utils::guard::kost_guard;
+
+// Import storage
+use crate::storage::SharedStorage;
+
+// Handler to upload a kost image, send the file as multipart field "image"
+pub async fn upload_kost_image(
+    Extension(db): Extension<MySqlPool>,
+    Extension(claims): Extension<Claims>,
+    Extension(storage): Extension<SharedStorage>,
+    Path(kost_id): Path<Uuid>,
+    mut multipart: Multipart,
+) -> ApiResult {
+    // Guard, so only kost owner can upload images
+    kost_guard(&db, &claims, kost_id).await?;
+
+    // Find the image field
+    let field = loop {
+        match multipart.next_field().await {
+            Ok(Some(field)) if field.name() == Some("image") => break field,
+            Ok(Some(_)) => continue,
+            Ok(None) => return Err(AppError::field("image", "Image file is required")),
+            Err(e) => return Err(AppError::Status(e.status(), e.body_text())),
+        }
+    };
+
+    // Read and check the size
+    let bytes = field
+        .bytes()
+        .await
+        .map_err(|e| AppError::Status(e.status(), e.body_text()))?;
+
+    if bytes.is_empty() {
+        return Err(AppError::field("image", "Image file cannot be empty"));
+    }
+
+    if bytes.len() > MAX_IMAGE_BYTES {
+        return Err(AppError::Status(
+            StatusCode::PAYLOAD_TOO_LARGE,
+            "Image cannot be more than 5 MB".to_string(),
+        ));
+    }
+
+    // Check the file content, the content type of the field is set by the client
+    let (content_type, extension) = detect_image_type(&bytes).ok_or_else(|| AppError::Status(
+        StatusCode::UNSUPPORTED_MEDIA_TYPE,
+        "Image must be a JPEG, PNG or WebP file".to_string(),
+    ))?;
+
+    // Save the object first, the row only points to stored images
+    let image_id = Uuid::new_v4();
+    let object_key = format!("kosts/{}/{}.{}", kost_id, image_id, extension);
+
+    if let Err(e) = storage.put(&object_key, content_type, bytes).await {
+        eprintln!("Storage error: {}", e);
+        return Err(AppError::Internal("Failed to store image".to_string()));
+    }
+
+    let result = sqlx::query!(
+        "INSERT INTO Kost_Images (id, kost_id, object_key) VALUES (?, ?, ?)",
+        image_id,
+        kost_id,
+        object_key
+    )
+    .execute(&db)
+    .await;
+
+    if let Err(e) = result {
+        // Do not keep an object without row
+        if let Err(e) = storage.delete(&object_key).await {
+            eprintln!("Storage error: {}", e);
+        }
+
+        return Err(e.into());
+    }
+
+    // Get newly uploaded image
+    let image = sqlx::query_as!(
+        KostImage,
+        r#"
+        SELECT id AS "id: Uuid", kost_id AS "kost_id: Uuid", object_key, created_at, updated_at
+        FROM Kost_Images
+        WHERE id = ?
+        "#,
+        image_id
+    )
+    .fetch_one(&db)
+    .await?;
+
+    let response = KostImageResponse {
+        id: image.id,
+        kost_id: image.kost_id,
+        url: storage.url(&image.object_key),
+        object_key: image.object_key,
+        created_at: image.created_at,
+        updated_at: image.updated_at,
+    };
+
+    Ok((
+        // Send 201 response Created
+        StatusCode::CREATED,
+        Json(ApiResponse::success(
+            "Image uploaded successfully",
+            json!(response)))
+    ))
+}
+
+// Handler to get all images of a kost
+pub async fn get_kost_images(
+    Extension(db): Extension<MySqlPool>,
+    Extension(claims): Extension<Claims>,
+    Extension(storage): Extension<SharedStorage>,
+    Path(kost_id): Path<Uuid>,
+) -> ApiResult {
+    // Guard
+    kost_guard(&db, &claims, kost_id).await?;
+
+    let images = sqlx::query_as!(
+        KostImage,
+        r#"
+        SELECT id AS "id: Uuid", kost_id AS "kost_id: Uuid", object_key, created_at, updated_at
+        FROM Kost_Images
+        WHERE kost_id = ?
+        ORDER BY created_at ASC
+        "#,
+        kost_id
+    )
+    .fetch_all(&db)
+    .await?;
+
+    let response = images
+        .into_iter()
+        .map(|image| KostImageResponse {
+            id: image.id,
+            kost_id: image.kost_id,
+            url: storage.url(&image.object_key),
+            object_key: image.object_key,
+            created_at: image.created_at,
+            updated_at: image.updated_at,
+        })
+        .collect::<Vec<KostImageResponse>>();
+
+    Ok((
+        // Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "Images List",
+            json!(response)))
+    ))
+}
+
+// Handler to delete a kost image
+pub async fn delete_kost_image(
+    Extension(db): Extension<MySqlPool>,
+    Extension(claims): Extension<Claims>,
+    Extension(storage): Extension<SharedStorage>,
+    Path(path): Path<KostImagePath>,
+) -> ApiResult {
+    // Guard
+    kost_guard(&db, &claims, path.kost_id).await?;
+
+    let image = sqlx::query!(
+        r#"
+        SELECT id AS "id: Uuid", object_key
+        FROM Kost_Images
+        WHERE id = ? AND kost_id = ?
+        "#,
+        path.image_id,
+        path.kost_id
+    )
+    .fetch_one(&db)
+    .await
+    .or_not_found("Image with provided id is not found")?;
+
+    sqlx::query!(
+        "DELETE FROM Kost_Images WHERE id = ?",
+        image.id
+    )
+    .execute(&db)
+    .await?;
+
+    // The row is gone, a stale object is only logged
+    if let Err(e) = storage.delete(&image.object_key).await {
+        eprintln!("Storage error: {}", e);
+    }
+
+    Ok((
+        // Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "Image deleted successfully",
+            json!(null)))
+    ))
+}
\ No newline at end of file
@@ -1,13 +1,13 @@
 use axum::{
     Extension,
     Json,
-    http::StatusCode
+    http::{header::RETRY_AFTER, StatusCode},
+    response::{IntoResponse, Response},
 };
 use sqlx::MySqlPool;
 use bcrypt::verify;
-use validator::Validate;
-use std::collections::HashMap;
 use serde_json::{Value, json};
+use std::net::IpAddr;
 use uuid::Uuid;
 
 //Import login schema request and response
@@ -17,40 +17,47 @@ use crate::schemas::login_schema::{
     LoginResponse
 };
 
+//Import app config
+use crate::config::app_config;
+
 //Import utils to generate and validate token
 use crate::utils::{
     jwt::generate_token,
+    refresh_token::issue_refresh_token,
     response::ApiResponse,
 };
 
+//Import login throttling helpers
+use crate::utils::{
+    client_ip::ClientIp,
+    login_throttle::{clear_failed_logins, locked_for, record_failed_login_tx},
+};
+
+//Import validated JSON extractor
+use crate::utils::validated_json::ValidatedJson;
+
 pub async fn login(
     Extension(db): Extension<MySqlPool>,
-    Json(payload): Json<LoginRequest>
-) -> (StatusCode, Json<ApiResponse<Value>>) {
-    //Validate the request
-    if let Err(e) = payload.validate() {
-        let mut field_errors: HashMap<String, Vec<String>> = HashMap::new();
-
-        //Collect all the errors
-        for (field, errors) in e.field_errors() {
-            let message = errors
-                .iter()
-                .filter_map(|e| e.message.as_ref())
-                .map(|m| m.to_string())
-                .collect::<Vec<String>>();
-
-            field_errors.insert(field.to_string(), message);
-        }
+    ClientIp(ip): ClientIp,
+    ValidatedJson(payload): ValidatedJson<LoginRequest>
+) -> Response {
+    //Failed logins are counted per email, whatever the letter case
+    let email = payload.email.trim().to_lowercase();
 
-        return (
-            //Send 422 response Unprocessable Entity
-            StatusCode::UNPROCESSABLE_ENTITY,
-            Json(ApiResponse{
-                status: false,
-                message: "Failed to validate".to_string(),
-                data: Some(json!(field_errors)),
-            }),
-        );
+    //Refuse the attempt while the email or the IP is locked, even with the right password
+    match locked_for(&db, &email, ip).await {
+        Ok(Some(retry_after)) => return too_many_attempts(retry_after),
+        Ok(None) => {},
+        Err(e) => {
+            eprintln!("Database error: {}", e);
+            return (
+                //Send 500 response Internal Server Error
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::<Value>::error(
+                    "System Error"
+                ))
+            ).into_response();
+        }
     }
 
     //Fetch user by email
@@ -61,7 +68,9 @@ pub async fn login(
             u.name,
             u.email,
             u.password,
+            u.email_verified_at,
             u.role_id AS "role_id: Uuid",
+            u.token_version AS "token_version: u32",
             r.name AS "role"
         FROM Users u
         JOIN Roles r ON r.id = u.role_id
@@ -74,23 +83,17 @@ pub async fn login(
     {
         Ok(user) => user,
         Err(sqlx::Error::RowNotFound) => {
-            return (
-                // Send 401 response Unautorized
-                StatusCode::UNAUTHORIZED,
-                Json(ApiResponse::error(
-                    "Email or password is wrong",
-                )),
-            );
+            return failed_login(&db, &email, ip, None).await;
         },
         Err(e) => {
             eprintln!("Database error: {}", e);
             return (
                 //Send 500 response Internal Server Error
                 StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ApiResponse::error(
+                Json(ApiResponse::<Value>::error(
                     "System Error"
                 ))
-            );
+            ).into_response();
         }
     };
 
@@ -111,7 +114,37 @@ pub async fn login(
     //Verify password using bcrypt
     match verify(payload.password, &user.password) {
         Ok(true) => {
-            match generate_token(user.id, user.role, permissions) {
+            if let Err(e) = clear_failed_logins(&db, &email).await {
+                eprintln!("Database error: {}", e);
+            }
+
+            // Checked after the password, so it does not tell whether an email is registered
+            if user.email_verified_at.is_none() {
+                return (
+                    //Send 403 response Forbidden
+                    StatusCode::FORBIDDEN,
+                    Json(ApiResponse::<Value>::error(
+                        "Please verify your email before logging in"
+                    ))
+                ).into_response();
+            }
+
+            // Store the refresh token first, so the client never gets an access token it cannot renew
+            let refresh_token = match issue_refresh_token(&db, user.id).await {
+                Ok((_, refresh_token)) => refresh_token,
+                Err(e) => {
+                    eprintln!("Database error: {}", e);
+                    return (
+                        //Send 500 response Internal Server Error
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        Json(ApiResponse::<Value>::error(
+                            "Failed to generate token"
+                        ))
+                    ).into_response();
+                }
+            };
+
+            match generate_token(user.id, user.role, permissions, user.token_version) {
                 Ok(token) => {
                     let response = LoginResponse {
                         user: UserResponse {
@@ -120,6 +153,8 @@ pub async fn login(
                             email: user.email,
                         },
                         token,
+                        refresh_token,
+                        expires_in: app_config::get().access_token_minutes * 60,
                     };
                     (
                         StatusCode::OK,
@@ -127,36 +162,66 @@ pub async fn login(
                             "Login success", 
                             json!(response),
                         ))
-                    )
+                    ).into_response()
                 },
                 Err(e) => {
                     eprintln!("JWT generation error: {}", e);
                     (
                         //Send 500 response Internal Server Error
                         StatusCode::INTERNAL_SERVER_ERROR,
-                        Json(ApiResponse::error(
+                        Json(ApiResponse::<Value>::error(
                             "Failed to generate token"
                         ))
-                    )
+                    ).into_response()
                 }
             }
         },
-        Ok(false) => (
-
-            //Send 401 response Unauthorized
-            StatusCode::UNAUTHORIZED,
-            Json(ApiResponse::error(
-                "Email or password is wrong"
-            ))
-        ),
+        Ok(false) => failed_login(&db, &email, ip, Some(user.id)).await,
         Err(_) => (
 
             //Send 500 response Internal Server Error
             StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ApiResponse::error(
+            Json(ApiResponse::<Value>::error(
                 "Failed to verify password"
             ))
-        )
+        ).into_response()
     }
 }
 
+//Count the failed login, the failure that reaches the limit already gets the lockout response
+async fn failed_login(
+    db: &MySqlPool,
+    email: &str,
+    ip: IpAddr,
+    user_id: Option<Uuid>,
+) -> Response {
+    match record_failed_login_tx(db, email, ip, user_id).await {
+        Ok(Some(retry_after)) => too_many_attempts(retry_after),
+        result => {
+            if let Err(e) = result {
+                eprintln!("Database error: {}", e);
+            }
+
+            (
+                //Send 401 response Unauthorized
+                StatusCode::UNAUTHORIZED,
+                Json(ApiResponse::<Value>::error(
+                    "Email or password is wrong"
+                ))
+            ).into_response()
+        }
+    }
+}
+
+//Response for a locked email or IP, Retry-After tells the client when to try again
+fn too_many_attempts(retry_after: i64) -> Response {
+    (
+        //Send 429 response Too Many Requests
+        StatusCode::TOO_MANY_REQUESTS,
+        [(RETRY_AFTER, retry_after.to_string())],
+        Json(ApiResponse::<Value>::error(
+            &format!("Too many failed login attempts, try again in {} seconds", retry_after)
+        ))
+    ).into_response()
+}
+
@@ -0,0 +1,587 @@
+use axum::{
+    Extension,
+    Json,
+    http::StatusCode,
+    extract::Query,
+};
+
+use bcrypt::{hash, verify};
+use sqlx::MySqlPool;
+use serde_json::json;
+
+use uuid::Uuid;
+
+// Import claims from utils
+use crate::utils::jwt::Claims;
+
+// Import models
+use crate::models::{
+    booking::TenantBooking,
+    invoice::Invoice,
+    payment::Payment,
+};
+
+// Import invoice schema
+use crate::schemas::invoice_schema::{
+    InvoiceQuery,
+    parse_period,
+};
+
+// Import enums
+use crate::schemas::{
+    booking_schema::PaymentStatus,
+    payment_schema::PaymentMethod,
+};
+
+// Import me schema
+use crate::schemas::me_schema::{
+    MeUpdateRequest,
+    MeResponse,
+    ChangePasswordRequest,
+    DeleteAccountRequest,
+};
+
+// Import API Response
+use crate::utils::response::ApiResponse;
+
+// Import app error
+use crate::utils::error::{
+    ApiResult,
+    AppError,
+    DbResultExt,
+};
+
+// Import validated JSON extractor
+use crate::utils::validated_json::ValidatedJson;
+
+// Import mailer
+use crate::mailer::SharedMailer;
+
+// Import helpers
+use crate::utils::{
+    audit::record_audit,
+    email_verification::{issue_verification_token, send_verification_email},
+    token_version::bump_user_token_version,
+};
+
+// Handler to get the bookings of the current user
+pub async fn get_my_bookings(
+    Extension(db): Extension<MySqlPool>,
+    Extension(claims): Extension<Claims>,
+) -> ApiResult {
+    let bookings = sqlx::query_as!(
+        TenantBooking,
+        r#"
+        SELECT
+            b.id AS "id: Uuid",
+            k.id AS "kost_id: Uuid",
+            k.kost_name,
+            b.room_id AS "room_id: Uuid",
+            r.room_number AS "room_number: u32",
+            b.check_in,
+            b.check_out,
+            b.monthly_rent,
+            r.currency,
+            b.payment_status AS "payment_status: PaymentStatus",
+            b.created_at,
+            b.updated_at
+        FROM Bookings b
+        JOIN Rooms r ON r.id = b.room_id
+        JOIN Kosts k ON k.id = r.kost_id
+        WHERE b.user_id = ?
+        ORDER BY b.check_in DESC
+        "#,
+        claims.sub
+    )
+    .fetch_all(&db)
+    .await?;
+
+    Ok((
+        // Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "My Bookings",
+            json!(bookings)))
+    ))
+}
+
+// Handler to get the invoices of the current user
+pub async fn get_my_invoices(
+    Extension(db): Extension<MySqlPool>,
+    Extension(claims): Extension<Claims>,
+    Query(query): Query<InvoiceQuery>,
+) -> ApiResult {
+    // Billing month filter
+    let period_start = match query.period.as_deref() {
+        Some(period) => Some(
+            parse_period(period)
+                .ok_or_else(|| AppError::field("period", "Period must be in YYYY-MM format"))?
+        ),
+        None => None,
+    };
+
+    let invoices = sqlx::query_as!(
+        Invoice,
+        r#"
+        SELECT
+            i.id AS "id: Uuid",
+            i.booking_id AS "booking_id: Uuid",
+            b.room_id AS "room_id: Uuid",
+            r.room_number AS "room_number: u32",
+            b.user_id AS "user_id: Uuid",
+            i.period_start,
+            i.amount,
+            i.paid_amount,
+            (i.amount - i.paid_amount) AS "outstanding!: u64",
+            i.due_date,
+            i.status AS "status: PaymentStatus",
+            i.created_at,
+            i.updated_at
+        FROM Invoices i
+        JOIN Bookings b ON b.id = i.booking_id
+        JOIN Rooms r ON r.id = b.room_id
+        WHERE b.user_id = ?
+            AND (? IS NULL OR i.status = ?)
+            AND (? IS NULL OR i.period_start = ?)
+        ORDER BY i.period_start DESC
+        "#,
+        claims.sub,
+        query.status,
+        query.status,
+        period_start,
+        period_start
+    )
+    .fetch_all(&db)
+    .await?;
+
+    Ok((
+        // Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "My Invoices",
+            json!(invoices)))
+    ))
+}
+
+// Handler to get the payment history of the current user
+pub async fn get_my_payments(
+    Extension(db): Extension<MySqlPool>,
+    Extension(claims): Extension<Claims>,
+) -> ApiResult {
+    let payments = sqlx::query_as!(
+        Payment,
+        r#"
+        SELECT
+            p.id AS "id: Uuid",
+            p.invoice_id AS "invoice_id: Uuid",
+            p.amount,
+            p.method AS "method: PaymentMethod",
+            p.reference,
+            p.paid_at,
+            p.recorded_by AS "recorded_by: Uuid",
+            p.created_at,
+            p.updated_at
+        FROM Payments p
+        JOIN Invoices i ON i.id = p.invoice_id
+        JOIN Bookings b ON b.id = i.booking_id
+        WHERE b.user_id = ?
+        ORDER BY p.paid_at DESC
+        "#,
+        claims.sub
+    )
+    .fetch_all(&db)
+    .await?;
+
+    Ok((
+        // Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "My Payments",
+            json!(payments)))
+    ))
+}
+
+// Helper to check a password of the current user, a wrong password is a validation error on the given field
+fn check_password(field: &str, password: &str, hashed: &str) -> Result<(), AppError> {
+    match verify(password, hashed) {
+        Ok(true) => Ok(()),
+        Ok(false) => Err(AppError::field(field, "Password is wrong")),
+        Err(_) => Err(AppError::Internal("Failed to verify password".to_string())),
+    }
+}
+
+// Helper to get the profile of a user with the role, the permissions of the role and the number of owned kosts
+async fn find_me(
+    db: &MySqlPool,
+    user_id: Uuid,
+) -> Result<MeResponse, sqlx::Error> {
+    let user = sqlx::query!(
+        r#"
+        SELECT
+            u.id AS "id: Uuid",
+            u.name,
+            u.email,
+            u.email_verified_at,
+            u.phone,
+            u.avatar_url,
+            u.role_id AS "role_id: Uuid",
+            r.name AS "role",
+            (SELECT COUNT(*) FROM Kosts k WHERE k.user_id = u.id) AS "owned_kost_count!: i64",
+            u.created_at,
+            u.updated_at
+        FROM Users u
+        JOIN Roles r ON r.id = u.role_id
+        WHERE u.id = ?
+        "#,
+        user_id
+    )
+    .fetch_one(db)
+    .await?;
+
+    let permissions: Vec<String> = sqlx::query_scalar!(
+        r#"
+        SELECT p.name
+        FROM Role_Permissions rp
+        JOIN Permissions p ON p.id = rp.permission_id
+        WHERE rp.role_id = ?
+        ORDER BY p.name ASC
+        "#,
+        user.role_id
+    )
+    .fetch_all(db)
+    .await?;
+
+    Ok(MeResponse {
+        id: user.id,
+        name: user.name,
+        email: user.email,
+        email_verified_at: user.email_verified_at,
+        phone: user.phone,
+        avatar_url: user.avatar_url,
+        role: user.role,
+        permissions,
+        owned_kost_count: user.owned_kost_count,
+        created_at: user.created_at,
+        updated_at: user.updated_at,
+    })
+}
+
+// Handler to get the profile of the current user
+pub async fn get_me(
+    Extension(db): Extension<MySqlPool>,
+    Extension(claims): Extension<Claims>,
+) -> ApiResult {
+    let me = find_me(&db, claims.sub)
+        .await
+        .or_not_found("User not found")?;
+
+    Ok((
+        // Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "My Profile",
+            json!(me)))
+    ))
+}
+
+// Helper to update the profile of the current user in one transaction.
+// A new email has to be verified again, the token to email is returned
+async fn update_me_tx(
+    db: &MySqlPool,
+    user_id: Uuid,
+    name: &str,
+    email: &str,
+    phone: Option<&str>,
+    avatar_url: Option<&str>,
+    email_changed: bool,
+) -> Result<Option<String>, sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    sqlx::query!(
+        "UPDATE Users SET name = ?, email = ?, phone = ?, avatar_url = ? WHERE id = ?",
+        name,
+        email,
+        phone,
+        avatar_url,
+        user_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    let token = if email_changed {
+        sqlx::query!(
+            "UPDATE Users SET email_verified_at = NULL WHERE id = ?",
+            user_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            "DELETE FROM Email_Verifications WHERE user_id = ? AND used_at IS NULL",
+            user_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        Some(issue_verification_token(&mut *tx, user_id).await?)
+    } else {
+        None
+    };
+
+    record_audit(
+        &mut *tx,
+        Some(user_id),
+        "user.update_self",
+        "user",
+        Some(user_id),
+        json!({ "name": name, "phone": phone, "avatar_url": avatar_url, "email_changed": email_changed }),
+    )
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(token)
+}
+
+// Handler to update the profile of the current user
+pub async fn update_me(
+    Extension(db): Extension<MySqlPool>,
+    Extension(claims): Extension<Claims>,
+    Extension(mailer): Extension<SharedMailer>,
+    ValidatedJson(payload): ValidatedJson<MeUpdateRequest>,
+) -> ApiResult {
+    let user = sqlx::query!(
+        r#"
+        SELECT id AS "id: Uuid", name, email, phone, avatar_url
+        FROM Users
+        WHERE id = ?
+        "#,
+        claims.sub
+    )
+    .fetch_one(&db)
+    .await
+    .or_not_found("User not found")?;
+
+    let name = payload.name.unwrap_or(user.name);
+    let email = payload.email.unwrap_or_else(|| user.email.clone());
+    let email_changed = !email.eq_ignore_ascii_case(&user.email);
+
+    // Missing keeps the old value, empty removes it
+    let phone = match payload.phone {
+        Some(phone) if phone.is_empty() => None,
+        Some(phone) => Some(phone),
+        None => user.phone,
+    };
+    let avatar_url = match payload.avatar_url {
+        Some(avatar_url) if avatar_url.is_empty() => None,
+        Some(avatar_url) => Some(avatar_url),
+        None => user.avatar_url,
+    };
+
+    // Check email uniqueness
+    if email_changed {
+        let email_exist = sqlx::query!(
+            "SELECT id FROM Users WHERE email = ? AND id != ?",
+            email,
+            user.id
+        )
+        .fetch_optional(&db)
+        .await?;
+
+        if email_exist.is_some() {
+            return Err(AppError::Conflict("Email has been registered".to_string()));
+        }
+    }
+
+    let token = update_me_tx(&db, user.id, &name, &email, phone.as_deref(), avatar_url.as_deref(), email_changed)
+        .await
+        .or_conflict("Email has been registered")?;
+
+    if let Some(token) = token {
+        send_verification_email(mailer, &name, &email, &token);
+    }
+
+    let message = if email_changed {
+        "Profile updated, please verify your new email"
+    } else {
+        "Profile updated successfully"
+    };
+
+    // Get new profile data
+    let me = find_me(&db, user.id).await?;
+
+    Ok((
+        // Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            message,
+            json!(me)))
+    ))
+}
+
+// Helper to set the new password in one transaction, every session of the user is ended
+async fn change_password_tx(
+    db: &MySqlPool,
+    user_id: Uuid,
+    password: &str,
+) -> Result<(), sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    sqlx::query!(
+        "UPDATE Users SET password = ? WHERE id = ?",
+        password,
+        user_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query!(
+        "UPDATE Refresh_Tokens SET revoked_at = UTC_TIMESTAMP() WHERE user_id = ? AND revoked_at IS NULL",
+        user_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    bump_user_token_version(&mut *tx, user_id).await?;
+
+    record_audit(
+        &mut *tx,
+        Some(user_id),
+        "user.password_change",
+        "user",
+        Some(user_id),
+        json!({}),
+    )
+    .await?;
+
+    tx.commit().await
+}
+
+// Handler to change the password of the current user, the current password is required
+pub async fn change_my_password(
+    Extension(db): Extension<MySqlPool>,
+    Extension(claims): Extension<Claims>,
+    ValidatedJson(payload): ValidatedJson<ChangePasswordRequest>,
+) -> ApiResult {
+    let current: String = sqlx::query_scalar!(
+        "SELECT password FROM Users WHERE id = ?",
+        claims.sub
+    )
+    .fetch_one(&db)
+    .await?;
+
+    check_password("current_password", &payload.current_password, &current)?;
+
+    // Hash password with Bcrypt
+    let password = hash(&payload.new_password, 10)
+        .map_err(|_| AppError::Internal("Failed to encrypt the password".to_string()))?;
+
+    change_password_tx(&db, claims.sub, &password).await?;
+
+    Ok((
+        // Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "Password changed successfully, please login again",
+            json!(null)))
+    ))
+}
+
+// Helper to delete the current user, the audit row is written first and keeps the target id
+async fn delete_me_tx(
+    db: &MySqlPool,
+    user_id: Uuid,
+    email: &str,
+) -> Result<(), sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    record_audit(
+        &mut *tx,
+        Some(user_id),
+        "user.delete_self",
+        "user",
+        Some(user_id),
+        json!({ "email": email }),
+    )
+    .await?;
+
+    sqlx::query!(
+        "DELETE FROM Users WHERE id = ?",
+        user_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await
+}
+
+// Handler to delete the account of the current user, the password is required
+pub async fn delete_me(
+    Extension(db): Extension<MySqlPool>,
+    Extension(claims): Extension<Claims>,
+    ValidatedJson(payload): ValidatedJson<DeleteAccountRequest>,
+) -> ApiResult {
+    let user = sqlx::query!(
+        r#"
+        SELECT
+            u.email,
+            u.password,
+            r.name AS "role",
+            (SELECT COUNT(*) FROM Kosts k WHERE k.user_id = u.id) AS "kost_count!: i64",
+            (
+                SELECT COUNT(*)
+                FROM Invoices i
+                JOIN Bookings b ON b.id = i.booking_id
+                WHERE b.user_id = u.id
+            ) AS "invoice_count!: i64"
+        FROM Users u
+        JOIN Roles r ON r.id = u.role_id
+        WHERE u.id = ?
+        "#,
+        claims.sub
+    )
+    .fetch_one(&db)
+    .await?;
+
+    check_password("password", &payload.password, &user.password)?;
+
+    // Deleting the user would delete the kosts with their rooms and bookings
+    if user.kost_count > 0 {
+        return Err(AppError::Conflict("Delete your kosts before deleting your account".to_string()));
+    }
+
+    // Invoices and payments are kept, they are the billing history of the kost
+    if user.invoice_count > 0 {
+        return Err(AppError::Conflict("Your bookings have invoices, the account cannot be deleted".to_string()));
+    }
+
+    // Keep at least one admin, otherwise nobody can manage the users anymore
+    if user.role == "ADMIN" {
+        let admin_count = sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*) AS "count!: i64"
+            FROM Users u
+            JOIN Roles r ON r.id = u.role_id
+            WHERE r.name = 'ADMIN'
+            "#
+        )
+        .fetch_one(&db)
+        .await?;
+
+        if admin_count <= 1 {
+            return Err(AppError::Conflict("The last admin account cannot be deleted".to_string()));
+        }
+    }
+
+    delete_me_tx(&db, claims.sub, &user.email)
+        .await
+        .or_referenced("Your bookings have invoices, the account cannot be deleted")?;
+
+    Ok((
+        // Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "Your account has been deleted",
+            json!(null)))
+    ))
+}
@@ -2,4 +2,19 @@ pub mod register_user_handler;
 pub mod login_handler;
 pub mod user_handler;
 pub mod kost_handler;
-pub mod room_handler;
\ No newline at end of file
+pub mod room_handler;
+pub mod booking_handler;
+pub mod invoice_handler;
+pub mod payment_handler;
+pub mod dashboard_handler;
+pub mod kost_image_handler;
+pub mod me_handler;
+pub mod public_handler;
+pub mod booking_request_handler;
+pub mod role_handler;
+pub mod permission_handler;
+pub mod audit_log_handler;
+pub mod token_handler;
+pub mod password_handler;
+pub mod email_verification_handler;
+pub mod owner_application_handler;
\ No newline at end of file
@@ -0,0 +1,402 @@
+use axum::{
+    Extension,
+    Json,
+    http::StatusCode,
+    extract::{Path, Query},
+};
+
+use sqlx::MySqlPool;
+use serde_json::json;
+
+use uuid::Uuid;
+
+// Import claims from utils
+use crate::utils::jwt::Claims;
+
+// Import owner application model
+use crate::models::owner_application::OwnerApplication;
+
+// Import owner application schema
+use crate::schemas::owner_application_schema::{
+    OwnerApplicationNewRequest,
+    OwnerApplicationRejectRequest,
+    OwnerApplicationQuery,
+    OwnerApplicationStatus,
+};
+
+// Import API Response
+use crate::utils::response::ApiResponse;
+
+// Import app error
+use crate::utils::error::{
+    ApiResult,
+    AppError,
+    DbResultExt,
+};
+
+// Import validated JSON extractor
+use crate::utils::validated_json::ValidatedJson;
+
+// Import helpers
+use crate::utils::{
+    audit::record_audit,
+    token_version::bump_user_token_version,
+};
+
+// Result of an approval, everything except Approved leaves the application untouched
+enum ApproveOutcome {
+    Approved,
+    NotPending,
+    NotMember,
+}
+
+// Helper to get an owner application by id
+async fn find_owner_application(
+    db: &MySqlPool,
+    application_id: Uuid,
+) -> Result<OwnerApplication, sqlx::Error> {
+    sqlx::query_as!(
+        OwnerApplication,
+        r#"
+        SELECT
+            oa.id AS "id: Uuid",
+            oa.user_id AS "user_id: Uuid",
+            u.name AS "user_name",
+            u.email AS "user_email",
+            oa.business_name,
+            oa.business_address,
+            oa.phone,
+            oa.description,
+            oa.status AS "status: OwnerApplicationStatus",
+            oa.rejection_reason,
+            oa.decided_by AS "decided_by: Uuid",
+            oa.decided_at,
+            oa.created_at,
+            oa.updated_at
+        FROM Owner_Applications oa
+        JOIN Users u ON u.id = oa.user_id
+        WHERE oa.id = ?
+        "#,
+        application_id
+    )
+    .fetch_one(db)
+    .await
+}
+
+// Helper to approve an application, the user becomes an OWNER and the old tokens stop working,
+// so the next refresh carries the owner permissions. Everything runs in one transaction
+async fn approve_owner_application_tx(
+    db: &MySqlPool,
+    application_id: Uuid,
+    decided_by: Uuid,
+) -> Result<ApproveOutcome, sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    let application = sqlx::query!(
+        r#"
+        SELECT user_id AS "user_id: Uuid", status AS "status: OwnerApplicationStatus"
+        FROM Owner_Applications
+        WHERE id = ?
+        FOR UPDATE
+        "#,
+        application_id
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
+    if application.status != OwnerApplicationStatus::PENDING {
+        return Ok(ApproveOutcome::NotPending);
+    }
+
+    // Only members are upgraded, an admin must never be turned into an owner
+    let role: String = sqlx::query_scalar!(
+        r#"
+        SELECT r.name
+        FROM Users u
+        JOIN Roles r ON r.id = u.role_id
+        WHERE u.id = ?
+        FOR UPDATE
+        "#,
+        application.user_id
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
+    if role != "MEMBER" {
+        return Ok(ApproveOutcome::NotMember);
+    }
+
+    sqlx::query!(
+        "UPDATE Users SET role_id = (SELECT id FROM Roles WHERE name = 'OWNER') WHERE id = ?",
+        application.user_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    bump_user_token_version(&mut *tx, application.user_id).await?;
+
+    sqlx::query!(
+        r#"
+        UPDATE Owner_Applications
+        SET status = 'APPROVED', decided_by = ?, decided_at = CURRENT_TIMESTAMP
+        WHERE id = ?
+        "#,
+        decided_by,
+        application_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    record_audit(
+        &mut *tx,
+        Some(decided_by),
+        "owner_application.approve",
+        "owner_application",
+        Some(application_id),
+        json!({ "user_id": application.user_id, "from_role": role, "to_role": "OWNER" }),
+    )
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(ApproveOutcome::Approved)
+}
+
+// Helper to reject a pending application, returns false when it was already decided
+async fn reject_owner_application_tx(
+    db: &MySqlPool,
+    application_id: Uuid,
+    decided_by: Uuid,
+    reason: Option<&str>,
+) -> Result<bool, sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    // The status check in the query keeps a concurrent approval from being overwritten
+    let result = sqlx::query!(
+        r#"
+        UPDATE Owner_Applications
+        SET status = 'REJECTED', rejection_reason = ?, decided_by = ?, decided_at = CURRENT_TIMESTAMP
+        WHERE id = ? AND status = 'PENDING'
+        "#,
+        reason,
+        decided_by,
+        application_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    if result.rows_affected() == 0 {
+        return Ok(false);
+    }
+
+    record_audit(
+        &mut *tx,
+        Some(decided_by),
+        "owner_application.reject",
+        "owner_application",
+        Some(application_id),
+        json!({ "reason": reason }),
+    )
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(true)
+}
+
+// Handler for a member to apply for the OWNER role
+pub async fn create_owner_application(
+    Extension(db): Extension<MySqlPool>,
+    Extension(claims): Extension<Claims>,
+    ValidatedJson(payload): ValidatedJson<OwnerApplicationNewRequest>,
+) -> ApiResult {
+    // One pending application per user
+    let pending = sqlx::query!(
+        r#"
+        SELECT id AS "id: Uuid"
+        FROM Owner_Applications
+        WHERE user_id = ? AND status = 'PENDING'
+        LIMIT 1
+        "#,
+        claims.sub
+    )
+    .fetch_optional(&db)
+    .await?;
+
+    if pending.is_some() {
+        return Err(AppError::Conflict("You already have a pending owner application".to_string()));
+    }
+
+    // Insert new owner application to database
+    let application_id = Uuid::new_v4();
+
+    sqlx::query!(
+        "INSERT INTO Owner_Applications (id, user_id, business_name, business_address, phone, description) VALUES (?, ?, ?, ?, ?, ?)",
+        application_id,
+        claims.sub,
+        payload.business_name,
+        payload.business_address,
+        payload.phone,
+        payload.description
+    )
+    .execute(&db)
+    .await?;
+
+    // Get newly created application
+    let application = find_owner_application(&db, application_id).await?;
+
+    Ok((
+        // Send 201 response Created
+        StatusCode::CREATED,
+        Json(ApiResponse::success(
+            "Owner application sent successfully",
+            json!(application)))
+    ))
+}
+
+// Handler to get the owner applications of the current user
+pub async fn get_my_owner_applications(
+    Extension(db): Extension<MySqlPool>,
+    Extension(claims): Extension<Claims>,
+) -> ApiResult {
+    let applications = sqlx::query_as!(
+        OwnerApplication,
+        r#"
+        SELECT
+            oa.id AS "id: Uuid",
+            oa.user_id AS "user_id: Uuid",
+            u.name AS "user_name",
+            u.email AS "user_email",
+            oa.business_name,
+            oa.business_address,
+            oa.phone,
+            oa.description,
+            oa.status AS "status: OwnerApplicationStatus",
+            oa.rejection_reason,
+            oa.decided_by AS "decided_by: Uuid",
+            oa.decided_at,
+            oa.created_at,
+            oa.updated_at
+        FROM Owner_Applications oa
+        JOIN Users u ON u.id = oa.user_id
+        WHERE oa.user_id = ?
+        ORDER BY oa.created_at DESC
+        "#,
+        claims.sub
+    )
+    .fetch_all(&db)
+    .await?;
+
+    Ok((
+        // Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "My Owner Applications",
+            json!(applications)))
+    ))
+}
+
+// Handler for admins to get the owner applications, oldest first so the queue is worked in order
+pub async fn get_owner_applications(
+    Extension(db): Extension<MySqlPool>,
+    Query(query): Query<OwnerApplicationQuery>,
+) -> ApiResult {
+    let applications = sqlx::query_as!(
+        OwnerApplication,
+        r#"
+        SELECT
+            oa.id AS "id: Uuid",
+            oa.user_id AS "user_id: Uuid",
+            u.name AS "user_name",
+            u.email AS "user_email",
+            oa.business_name,
+            oa.business_address,
+            oa.phone,
+            oa.description,
+            oa.status AS "status: OwnerApplicationStatus",
+            oa.rejection_reason,
+            oa.decided_by AS "decided_by: Uuid",
+            oa.decided_at,
+            oa.created_at,
+            oa.updated_at
+        FROM Owner_Applications oa
+        JOIN Users u ON u.id = oa.user_id
+        WHERE (? IS NULL OR oa.status = ?)
+        ORDER BY oa.created_at ASC
+        "#,
+        query.status,
+        query.status
+    )
+    .fetch_all(&db)
+    .await?;
+
+    Ok((
+        // Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "Owner Applications List",
+            json!(applications)))
+    ))
+}
+
+// Handler for admins to approve an application, which upgrades the user to OWNER
+pub async fn approve_owner_application(
+    Extension(db): Extension<MySqlPool>,
+    Extension(claims): Extension<Claims>,
+    Path(application_id): Path<Uuid>,
+) -> ApiResult {
+    let outcome = approve_owner_application_tx(&db, application_id, claims.sub)
+        .await
+        .or_not_found("Owner application with provided id is not found")?;
+
+    match outcome {
+        ApproveOutcome::Approved => {},
+        ApproveOutcome::NotPending => {
+            return Err(AppError::Conflict("Owner application has already been decided".to_string()));
+        },
+        ApproveOutcome::NotMember => {
+            return Err(AppError::Conflict("Only members can be upgraded to owner".to_string()));
+        },
+    }
+
+    // Get new application data
+    let application = find_owner_application(&db, application_id).await?;
+
+    Ok((
+        // Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "Owner application approved successfully",
+            json!(application)))
+    ))
+}
+
+// Handler for admins to reject an application
+pub async fn reject_owner_application(
+    Extension(db): Extension<MySqlPool>,
+    Extension(claims): Extension<Claims>,
+    Path(application_id): Path<Uuid>,
+    ValidatedJson(payload): ValidatedJson<OwnerApplicationRejectRequest>,
+) -> ApiResult {
+    // Check the application exist
+    find_owner_application(&db, application_id)
+        .await
+        .or_not_found("Owner application with provided id is not found")?;
+
+    let rejected = reject_owner_application_tx(&db, application_id, claims.sub, payload.reason.as_deref()).await?;
+
+    if !rejected {
+        return Err(AppError::Conflict("Owner application has already been decided".to_string()));
+    }
+
+    // Get new application data
+    let application = find_owner_application(&db, application_id).await?;
+
+    Ok((
+        // Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "Owner application rejected successfully",
+            json!(application)))
+    ))
+}
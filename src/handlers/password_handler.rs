@@ -0,0 +1,210 @@
+use axum::{
+    Extension,
+    Json,
+    http::StatusCode,
+};
+
+use bcrypt::hash;
+use sqlx::MySqlPool;
+use serde_json::json;
+
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+
+// Import password schema
+use crate::schemas::password_schema::{
+    ForgotPasswordRequest,
+    ResetPasswordRequest,
+};
+
+// Import API Response
+use crate::utils::response::ApiResponse;
+
+// Import app error
+use crate::utils::error::{
+    ApiResult,
+    AppError,
+};
+
+// Import validated JSON extractor
+use crate::utils::validated_json::ValidatedJson;
+
+// Import app config
+use crate::config::app_config;
+
+// Import mailer
+use crate::mailer::{send_in_background, Email, SharedMailer};
+
+// Import helpers
+use crate::utils::{
+    audit::record_audit,
+    secure_token::{hash_token, random_token},
+    token_version::bump_user_token_version,
+};
+
+// Helper to replace the pending reset tokens of the user with a new one, returns the token to email
+async fn create_password_reset_tx(
+    db: &MySqlPool,
+    user_id: Uuid,
+) -> Result<String, sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    // Only the latest link works
+    sqlx::query!(
+        "DELETE FROM Password_Resets WHERE user_id = ? AND used_at IS NULL",
+        user_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    let token = random_token();
+    let expires_at = Utc::now().naive_utc() + Duration::minutes(app_config::get().password_reset_minutes);
+
+    sqlx::query!(
+        "INSERT INTO Password_Resets (id, user_id, token_hash, expires_at) VALUES (?, ?, ?, ?)",
+        Uuid::new_v4(),
+        user_id,
+        hash_token(&token),
+        expires_at
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(token)
+}
+
+// Handler to request a password reset link.
+// The response is the same whether the email is registered or not, so it cannot be used to find accounts
+pub async fn forgot_password(
+    Extension(db): Extension<MySqlPool>,
+    Extension(mailer): Extension<SharedMailer>,
+    ValidatedJson(payload): ValidatedJson<ForgotPasswordRequest>,
+) -> ApiResult {
+    let user = sqlx::query!(
+        r#"
+        SELECT id AS "id: Uuid", name, email
+        FROM Users
+        WHERE email = ?
+        "#,
+        payload.email
+    )
+    .fetch_optional(&db)
+    .await?;
+
+    if let Some(user) = user {
+        let token = create_password_reset_tx(&db, user.id).await?;
+
+        let config = app_config::get();
+        let link = format!("{}/reset-password?token={}", config.frontend_url, token);
+
+        send_in_background(mailer, Email {
+            to: user.email,
+            subject: "Reset your password".to_string(),
+            body: format!(
+                "Hi {},\n\nWe received a request to reset your Kost Management password. \
+                Open the link below to choose a new password:\n\n{}\n\n\
+                The link expires in {} minutes and can only be used once. \
+                If you did not request a reset, you can ignore this email.",
+                user.name, link, config.password_reset_minutes
+            ),
+        });
+    }
+
+    Ok((
+        // Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "If the email is registered, a password reset link has been sent",
+            json!(null)))
+    ))
+}
+
+// Helper to set the new password with a reset token in one transaction, returns false when the token is not valid.
+// Every session of the user is ended, whoever knew the old password is logged out
+async fn reset_password_tx(
+    db: &MySqlPool,
+    token: &str,
+    password: &str,
+) -> Result<bool, sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    let reset = match sqlx::query!(
+        r#"
+        SELECT id AS "id: Uuid", user_id AS "user_id: Uuid"
+        FROM Password_Resets
+        WHERE token_hash = ? AND used_at IS NULL AND expires_at > UTC_TIMESTAMP()
+        FOR UPDATE
+        "#,
+        hash_token(token)
+    )
+    .fetch_optional(&mut *tx)
+    .await?
+    {
+        Some(reset) => reset,
+        None => return Ok(false),
+    };
+
+    sqlx::query!(
+        "UPDATE Users SET password = ? WHERE id = ?",
+        password,
+        reset.user_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query!(
+        "UPDATE Password_Resets SET used_at = UTC_TIMESTAMP() WHERE user_id = ? AND used_at IS NULL",
+        reset.user_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query!(
+        "UPDATE Refresh_Tokens SET revoked_at = UTC_TIMESTAMP() WHERE user_id = ? AND revoked_at IS NULL",
+        reset.user_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    bump_user_token_version(&mut *tx, reset.user_id).await?;
+
+    record_audit(
+        &mut *tx,
+        Some(reset.user_id),
+        "user.password_reset",
+        "user",
+        Some(reset.user_id),
+        json!({ "reset_id": reset.id }),
+    )
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(true)
+}
+
+// Handler to set a new password with the token from the reset link
+pub async fn reset_password(
+    Extension(db): Extension<MySqlPool>,
+    ValidatedJson(payload): ValidatedJson<ResetPasswordRequest>,
+) -> ApiResult {
+    // Hash password with Bcrypt
+    let password = hash(&payload.password, 10)
+        .map_err(|_| AppError::Internal("Failed to encrypt the password".to_string()))?;
+
+    let reset = reset_password_tx(&db, &payload.token, &password).await?;
+
+    if !reset {
+        return Err(AppError::BadRequest("Reset token is not valid or has expired".to_string()));
+    }
+
+    Ok((
+        // Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "Password has been reset, please login with the new password",
+            json!(null)))
+    ))
+}
@@ -0,0 +1,290 @@
+use axum::{
+    Extension,
+    Json,
+    http::StatusCode,
+    extract::Path,
+};
+
+use sqlx::MySqlPool;
+use serde_json::json;
+
+use chrono::Utc;
+use uuid::Uuid;
+
+// Import claims from utils
+use crate::utils::jwt::Claims;
+
+// Import invoice model
+use crate::models::invoice::InvoicePath;
+
+// Import payment model
+use crate::models::payment::{
+    Payment,
+    PaymentPath,
+};
+
+// Import payment schema
+use crate::schemas::payment_schema::{
+    PaymentNewRequest,
+    PaymentNewResponse,
+    PaymentMethod,
+};
+
+// Import payment status enum
+use crate::schemas::booking_schema::PaymentStatus;
+
+// Import API Response
+use crate::utils::response::ApiResponse;
+
+// Import app error
+use crate::utils::error::{
+    ApiResult,
+    AppError,
+    DbResultExt,
+};
+
+// Import validated JSON extractor
+use crate::utils::validated_json::ValidatedJson;
+
+// Import ownership guard
+use crate::utils::guard::kost_guard;
+
+// Import invoice helpers
+use crate::utils::invoice::{
+    find_invoice,
+    refresh_invoice_balance,
+    sync_booking_payment_status,
+};
+
+// Helper to get a payment of the invoice by id
+async fn find_payment(
+    db: &MySqlPool,
+    invoice_id: Uuid,
+    payment_id: Uuid,
+) -> Result<Payment, sqlx::Error> {
+    sqlx::query_as!(
+        Payment,
+        r#"
+        SELECT
+            id AS "id: Uuid",
+            invoice_id AS "invoice_id: Uuid",
+            amount,
+            method AS "method: PaymentMethod",
+            reference,
+            paid_at,
+            recorded_by AS "recorded_by: Uuid",
+            created_at,
+            updated_at
+        FROM Payments
+        WHERE id = ? AND invoice_id = ?
+        "#,
+        payment_id,
+        invoice_id
+    )
+    .fetch_one(db)
+    .await
+}
+
+// Handler to record a payment against an invoice
+pub async fn create_payment(
+    Extension(db): Extension<MySqlPool>,
+    Extension(claims): Extension<Claims>,
+    Path(path): Path<InvoicePath>,
+    ValidatedJson(payload): ValidatedJson<PaymentNewRequest>,
+) -> ApiResult {
+    let (kost_id, invoice_id) = (path.kost_id, path.invoice_id);
+
+    // Guard, so only kost owner can record payments
+    kost_guard(&db, &claims, kost_id).await?;
+
+    // Start transaction, so the payment and the invoice balance change together
+    let mut tx = db.begin().await?;
+
+    // Lock the invoice while the payment is recorded
+    let invoice = sqlx::query!(
+        r#"
+        SELECT
+            i.id AS "id: Uuid",
+            i.booking_id AS "booking_id: Uuid",
+            i.amount,
+            i.paid_amount,
+            i.status AS "status: PaymentStatus"
+        FROM Invoices i
+        JOIN Bookings b ON b.id = i.booking_id
+        JOIN Rooms r ON r.id = b.room_id
+        WHERE i.id = ? AND r.kost_id = ?
+        FOR UPDATE
+        "#,
+        invoice_id,
+        kost_id
+    )
+    .fetch_one(&mut *tx)
+    .await
+    .or_not_found("Invoice with provided id is not found")?;
+
+    match invoice.status {
+        PaymentStatus::CANCELLED => {
+            return Err(AppError::Conflict("Invoice has been cancelled".to_string()));
+        },
+        PaymentStatus::PAID => {
+            return Err(AppError::Conflict("Invoice has been paid".to_string()));
+        },
+        _ => {}
+    }
+
+    // Part payments are allowed, but never more than the outstanding balance
+    let outstanding = invoice.amount.saturating_sub(invoice.paid_amount);
+
+    if payload.amount > outstanding {
+        return Err(AppError::field(
+            "amount",
+            &format!("Payment amount exceeds the outstanding balance of {}", outstanding),
+        ));
+    }
+
+    // Insert new payment to database
+    let payment_id = Uuid::new_v4();
+    let paid_at = payload.paid_at.unwrap_or_else(|| Utc::now().naive_utc());
+
+    sqlx::query!(
+        "INSERT INTO Payments (id, invoice_id, amount, method, reference, paid_at, recorded_by) VALUES (?, ?, ?, ?, ?, ?, ?)",
+        payment_id,
+        invoice.id,
+        payload.amount,
+        payload.method,
+        payload.reference,
+        paid_at,
+        claims.sub
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    // Recompute the invoice balance and the booking payment status
+    refresh_invoice_balance(&mut *tx, invoice.id).await?;
+    sync_booking_payment_status(&mut *tx, invoice.booking_id).await?;
+
+    tx.commit().await?;
+
+    // Get newly recorded payment and the new invoice balance
+    let payment = find_payment(&db, invoice.id, payment_id).await?;
+    let invoice = find_invoice(&db, kost_id, invoice.id).await?;
+
+    let response = PaymentNewResponse {
+        id: payment.id,
+        invoice_id: payment.invoice_id,
+        amount: payment.amount,
+        method: payment.method,
+        reference: payment.reference,
+        paid_at: payment.paid_at,
+        recorded_by: payment.recorded_by,
+        invoice_status: invoice.status,
+        invoice_outstanding: invoice.outstanding,
+        created_at: payment.created_at,
+        updated_at: payment.updated_at,
+    };
+
+    Ok((
+        // Send 201 response Created
+        StatusCode::CREATED,
+        Json(ApiResponse::success(
+            "Payment recorded successfully",
+            json!(response)))
+    ))
+}
+
+// Handler to get all payments of an invoice
+pub async fn get_all_payments(
+    Extension(db): Extension<MySqlPool>,
+    Extension(claims): Extension<Claims>,
+    Path(path): Path<InvoicePath>,
+) -> ApiResult {
+    // Guard
+    kost_guard(&db, &claims, path.kost_id).await?;
+
+    // Check the invoice exist
+    let invoice = find_invoice(&db, path.kost_id, path.invoice_id)
+        .await
+        .or_not_found("Invoice with provided id is not found")?;
+
+    let payments = sqlx::query_as!(
+        Payment,
+        r#"
+        SELECT
+            id AS "id: Uuid",
+            invoice_id AS "invoice_id: Uuid",
+            amount,
+            method AS "method: PaymentMethod",
+            reference,
+            paid_at,
+            recorded_by AS "recorded_by: Uuid",
+            created_at,
+            updated_at
+        FROM Payments
+        WHERE invoice_id = ?
+        ORDER BY paid_at ASC
+        "#,
+        invoice.id
+    )
+    .fetch_all(&db)
+    .await?;
+
+    Ok((
+        // Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "Payments List",
+            json!(payments)))
+    ))
+}
+
+// Handler to delete a payment that was recorded by mistake
+pub async fn delete_payment(
+    Extension(db): Extension<MySqlPool>,
+    Extension(claims): Extension<Claims>,
+    Path(path): Path<PaymentPath>,
+) -> ApiResult {
+    // Guard
+    kost_guard(&db, &claims, path.kost_id).await?;
+
+    // Check the invoice and payment exist
+    let invoice = find_invoice(&db, path.kost_id, path.invoice_id)
+        .await
+        .or_not_found("Invoice with provided id is not found")?;
+
+    let payment = find_payment(&db, invoice.id, path.payment_id)
+        .await
+        .or_not_found("Payment with provided id is not found")?;
+
+    // Delete the payment and recompute the invoice balance in one transaction
+    delete_payment_tx(&db, payment.id, invoice.id, invoice.booking_id).await?;
+
+    Ok((
+        // Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "Payment deleted successfully",
+            json!(null)))
+    ))
+}
+
+// Helper to delete a payment and recompute the invoice balance in one transaction
+async fn delete_payment_tx(
+    db: &MySqlPool,
+    payment_id: Uuid,
+    invoice_id: Uuid,
+    booking_id: Uuid,
+) -> Result<(), sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    sqlx::query!(
+        "DELETE FROM Payments WHERE id = ?",
+        payment_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    refresh_invoice_balance(&mut *tx, invoice_id).await?;
+    sync_booking_payment_status(&mut *tx, booking_id).await?;
+
+    tx.commit().await
+}
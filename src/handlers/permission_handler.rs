@@ -0,0 +1,189 @@
+use axum::{
+    Extension,
+    Json,
+    http::StatusCode,
+    extract::Path,
+};
+
+use sqlx::MySqlPool;
+use serde_json::json;
+
+use uuid::Uuid;
+
+// Import claims from utils
+use crate::utils::jwt::Claims;
+
+// Import role models
+use crate::models::role::Permission;
+
+// Import role schema
+use crate::schemas::role_schema::PermissionNewRequest;
+
+// Import API Response
+use crate::utils::response::ApiResponse;
+
+// Import app error
+use crate::utils::error::{
+    ApiResult,
+    AppError,
+    DbResultExt,
+};
+
+// Import validated JSON extractor
+use crate::utils::validated_json::ValidatedJson;
+
+// Import audit and token helpers
+use crate::utils::{
+    audit::record_audit,
+    token_version::bump_permission_token_version,
+};
+
+// Helper to create a permission and record the audit log in one transaction
+async fn create_permission_tx(
+    db: &MySqlPool,
+    actor_id: Uuid,
+    permission_id: Uuid,
+    name: &str,
+) -> Result<(), sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    sqlx::query!(
+        "INSERT INTO Permissions (id, name) VALUES (?, ?)",
+        permission_id,
+        name
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    record_audit(&mut *tx, Some(actor_id), "permission.create", "permission", Some(permission_id), json!({ "name": name })).await?;
+
+    tx.commit().await
+}
+
+// Helper to delete a permission, the grants are removed by the cascade
+async fn delete_permission_tx(
+    db: &MySqlPool,
+    actor_id: Uuid,
+    permission: &Permission,
+) -> Result<(), sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    // Keep the roles that had the permission in the audit log
+    let roles = sqlx::query_scalar!(
+        r#"
+        SELECT r.name
+        FROM Role_Permissions rp
+        JOIN Roles r ON r.id = rp.role_id
+        WHERE rp.permission_id = ?
+        "#,
+        permission.id
+    )
+    .fetch_all(&mut *tx)
+    .await?;
+
+    // Bump before the grants are removed by the cascade
+    bump_permission_token_version(&mut *tx, permission.id).await?;
+
+    sqlx::query!(
+        "DELETE FROM Permissions WHERE id = ?",
+        permission.id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    record_audit(
+        &mut *tx,
+        Some(actor_id),
+        "permission.delete",
+        "permission",
+        Some(permission.id),
+        json!({ "name": permission.name, "roles": roles }),
+    )
+    .await?;
+
+    tx.commit().await
+}
+
+// Handler to get all permissions
+pub async fn get_all_permissions(
+    Extension(db): Extension<MySqlPool>,
+) -> ApiResult {
+    let permissions = sqlx::query_as!(
+        Permission,
+        r#"SELECT id AS "id: Uuid", name FROM Permissions ORDER BY name ASC"#
+    )
+    .fetch_all(&db)
+    .await?;
+
+    Ok((
+        // Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "Permissions List",
+            json!(permissions)))
+    ))
+}
+
+// Handler to create new permission
+pub async fn create_permission(
+    Extension(db): Extension<MySqlPool>,
+    Extension(claims): Extension<Claims>,
+    ValidatedJson(payload): ValidatedJson<PermissionNewRequest>,
+) -> ApiResult {
+    // Check the permission name is not taken
+    let permission_exist = sqlx::query!(
+        "SELECT id FROM Permissions WHERE name = ?",
+        payload.name
+    )
+    .fetch_optional(&db)
+    .await?;
+
+    if permission_exist.is_some() {
+        return Err(AppError::Conflict("Permission with provided name already exists".to_string()));
+    }
+
+    let permission_id = Uuid::new_v4();
+
+    create_permission_tx(&db, claims.sub, permission_id, &payload.name)
+        .await
+        .or_conflict("Permission with provided name already exists")?;
+
+    let response = Permission {
+        id: permission_id,
+        name: payload.name,
+    };
+
+    Ok((
+        // Send 201 response Created
+        StatusCode::CREATED,
+        Json(ApiResponse::success(
+            "Permission created successfully",
+            json!(response)))
+    ))
+}
+
+// Handler to delete a permission, it is revoked from every role
+pub async fn delete_permission(
+    Extension(db): Extension<MySqlPool>,
+    Extension(claims): Extension<Claims>,
+    Path(permission_id): Path<Uuid>,
+) -> ApiResult {
+    let permission = sqlx::query_as!(
+        Permission,
+        r#"SELECT id AS "id: Uuid", name FROM Permissions WHERE id = ?"#,
+        permission_id
+    )
+    .fetch_one(&db)
+    .await
+    .or_not_found("Permission with provided id is not found")?;
+
+    delete_permission_tx(&db, claims.sub, &permission).await?;
+
+    Ok((
+        // Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "Permission deleted successfully",
+            json!(null)))
+    ))
+}
@@ -0,0 +1,252 @@
+use std::collections::HashMap;
+
+use axum::{
+    Extension,
+    Json,
+    http::StatusCode,
+    extract::{Path, Query},
+};
+
+use sqlx::{MySql, MySqlPool, QueryBuilder};
+use serde_json::json;
+
+use uuid::Uuid;
+use validator::Validate;
+
+// Import kost model
+use crate::models::kost::PublicKost;
+
+// Import public schema
+use crate::schemas::public_schema::{
+    PublicKostQuery,
+    PublicKostResponse,
+    PublicKostImage,
+    PublicRoom,
+    DEFAULT_PER_PAGE,
+    like_pattern,
+};
+
+// Import API Response
+use crate::utils::response::ApiResponse;
+
+// Import app error
+use crate::utils::error::{
+    ApiResult,
+    AppError,
+    DbResultExt,
+};
+
+// Import storage
+use crate::storage::SharedStorage;
+
+// Image row of the batched query, grouped by kost afterwards
+#[derive(sqlx::FromRow)]
+struct KostImageRow {
+    kost_id: Uuid,
+    id: Uuid,
+    object_key: String,
+}
+
+// Available room row of the batched query, grouped by kost afterwards
+#[derive(sqlx::FromRow)]
+struct AvailableRoomRow {
+    kost_id: Uuid,
+    id: Uuid,
+    room_number: i32,
+    monthly_rent: u64,
+    deposit: Option<u64>,
+    currency: String,
+}
+
+// Helper to attach the images and available rooms to the kosts,
+// one query each for all the kosts so a page does not run two queries per kost
+async fn public_kost_details(
+    db: &MySqlPool,
+    storage: &SharedStorage,
+    kosts: Vec<PublicKost>,
+    min_price: Option<u64>,
+    max_price: Option<u64>,
+) -> Result<Vec<PublicKostResponse>, sqlx::Error> {
+    if kosts.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut query = QueryBuilder::<MySql>::new(
+        "SELECT kost_id, id, object_key FROM Kost_Images WHERE kost_id IN ("
+    );
+    let mut ids = query.separated(", ");
+    for kost in &kosts {
+        ids.push_bind(kost.id);
+    }
+    query.push(") ORDER BY created_at ASC");
+
+    let mut images: HashMap<Uuid, Vec<PublicKostImage>> = HashMap::new();
+
+    for image in query.build_query_as::<KostImageRow>().fetch_all(db).await? {
+        images.entry(image.kost_id).or_default().push(PublicKostImage {
+            id: image.id,
+            url: storage.url(&image.object_key),
+        });
+    }
+
+    let mut query = QueryBuilder::<MySql>::new(
+        "SELECT kost_id, id, room_number, monthly_rent, deposit, currency FROM Rooms WHERE room_vacancy = 'AVAILABLE' AND kost_id IN ("
+    );
+    let mut ids = query.separated(", ");
+    for kost in &kosts {
+        ids.push_bind(kost.id);
+    }
+    query.push(")");
+
+    if let Some(min_price) = min_price {
+        query.push(" AND monthly_rent >= ").push_bind(min_price);
+    }
+
+    if let Some(max_price) = max_price {
+        query.push(" AND monthly_rent <= ").push_bind(max_price);
+    }
+
+    query.push(" ORDER BY monthly_rent ASC, room_number ASC");
+
+    let mut rooms: HashMap<Uuid, Vec<PublicRoom>> = HashMap::new();
+
+    for room in query.build_query_as::<AvailableRoomRow>().fetch_all(db).await? {
+        rooms.entry(room.kost_id).or_default().push(PublicRoom {
+            id: room.id,
+            // Room numbers are validated to be positive
+            room_number: room.room_number as u32,
+            monthly_rent: room.monthly_rent,
+            deposit: room.deposit,
+            currency: room.currency,
+        });
+    }
+
+    Ok(kosts
+        .into_iter()
+        .map(|kost| PublicKostResponse {
+            images: images.remove(&kost.id).unwrap_or_default(),
+            available_rooms: rooms.remove(&kost.id).unwrap_or_default(),
+            id: kost.id,
+            kost_name: kost.kost_name,
+            kost_address: kost.kost_address,
+            kost_contact: kost.kost_contact,
+            kost_desc: kost.kost_desc,
+        })
+        .collect())
+}
+
+// Handler to search kosts that still have available rooms, no login needed
+pub async fn get_public_kosts(
+    Extension(db): Extension<MySqlPool>,
+    Extension(storage): Extension<SharedStorage>,
+    Query(query): Query<PublicKostQuery>,
+) -> ApiResult {
+    // Validate the request
+    query.validate().map_err(AppError::from)?;
+
+    // Empty search text means no filter
+    let city = query.city
+        .as_deref()
+        .filter(|city| !city.trim().is_empty())
+        .map(like_pattern);
+
+    let text = query.q
+        .as_deref()
+        .filter(|q| !q.trim().is_empty())
+        .map(like_pattern);
+
+    let page = query.page.unwrap_or(1);
+    let per_page = query.per_page.unwrap_or(DEFAULT_PER_PAGE);
+    let offset = u64::from(page - 1) * u64::from(per_page);
+
+    let kosts = sqlx::query_as!(
+        PublicKost,
+        r#"
+        SELECT
+            k.id AS "id: Uuid",
+            k.kost_name,
+            k.kost_address,
+            k.kost_contact,
+            k.kost_desc
+        FROM Kosts k
+        WHERE (? IS NULL OR k.kost_address LIKE ?)
+            AND (? IS NULL OR k.kost_name LIKE ? OR k.kost_address LIKE ? OR k.kost_desc LIKE ?)
+            AND EXISTS (
+                SELECT 1
+                FROM Rooms r
+                WHERE r.kost_id = k.id
+                    AND r.room_vacancy = 'AVAILABLE'
+                    AND (? IS NULL OR r.monthly_rent >= ?)
+                    AND (? IS NULL OR r.monthly_rent <= ?)
+            )
+        ORDER BY k.created_at DESC
+        LIMIT ? OFFSET ?
+        "#,
+        city,
+        city,
+        text,
+        text,
+        text,
+        text,
+        query.min_price,
+        query.min_price,
+        query.max_price,
+        query.max_price,
+        per_page,
+        offset
+    )
+    .fetch_all(&db)
+    .await?;
+
+    let response = public_kost_details(&db, &storage, kosts, query.min_price, query.max_price).await?;
+
+    Ok((
+        // Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "Kosts List",
+            json!({
+                "page": page,
+                "per_page": per_page,
+                "kosts": response,
+            })))
+    ))
+}
+
+// Handler to get a kost with its available rooms, no login needed
+pub async fn get_public_kost_by_id(
+    Extension(db): Extension<MySqlPool>,
+    Extension(storage): Extension<SharedStorage>,
+    Path(kost_id): Path<Uuid>,
+) -> ApiResult {
+    let kost = sqlx::query_as!(
+        PublicKost,
+        r#"
+        SELECT
+            id AS "id: Uuid",
+            kost_name,
+            kost_address,
+            kost_contact,
+            kost_desc
+        FROM Kosts
+        WHERE id = ?
+        "#,
+        kost_id
+    )
+    .fetch_one(&db)
+    .await
+    .or_not_found("Kost with provided id is not found")?;
+
+    let kost = public_kost_details(&db, &storage, vec![kost], None, None)
+        .await?
+        .pop()
+        .ok_or_else(|| AppError::Internal("Failed to get kost data".to_string()))?;
+
+    Ok((
+        // Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "Kost Details",
+            json!(kost)))
+    ))
+}
\ No newline at end of file
@@ -5,74 +5,77 @@ use axum::{
 };
 use sqlx::MySqlPool;
 use bcrypt::hash;
-use validator::Validate;
-use std::collections::HashMap;
-use serde_json::{json, Value};
+use serde_json::json;
 use uuid::Uuid;
 
 //Import register user request and response schema
 use crate::schemas::register_schema::{
     RegisterRequest,
     RegisterResponse,
-    RegRole,
 };
 
 //Import API response from utils
 use crate::utils::response::ApiResponse;
 
+//Import app error
+use crate::utils::error::{
+    ApiResult,
+    AppError,
+    DbResultExt,
+};
+
+//Import validated JSON extractor
+use crate::utils::validated_json::ValidatedJson;
+
+//Import mailer and email verification helpers
+use crate::mailer::SharedMailer;
+use crate::utils::email_verification::{issue_verification_token, send_verification_email};
+
+// Helper to insert the user and the verification token in one transaction, returns the token to email
+async fn register_user_tx(
+    db: &MySqlPool,
+    user_id: Uuid,
+    payload: &RegisterRequest,
+    password: &str,
+    role_id: Uuid,
+) -> Result<String, sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    sqlx::query!(
+        "INSERT INTO Users (id, name, email, password, role_id) VALUES (?, ?, ?, ?, ?)",
+        user_id,
+        payload.name,
+        payload.email,
+        password,
+        role_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    let token = issue_verification_token(&mut *tx, user_id).await?;
+
+    tx.commit().await?;
+
+    Ok(token)
+}
+
 pub async fn register(
     Extension(db): Extension<MySqlPool>,
-    Json(payload): Json<RegisterRequest>
-) -> (StatusCode, Json<ApiResponse<Value>>) {
-    //Validate request
-    if let Err(e) = payload.validate() {
-        let mut field_errors: HashMap<String, Vec<String>> = HashMap::new();
-
-        //Collect all the errors from validate
-        for (field, errors) in e.field_errors() {
-            let messages = errors
-                .iter()
-                .filter_map(|e|e.message.as_ref())
-                .map(|m| m.to_string())
-                .collect::<Vec<String>>();
-
-            field_errors.insert(field.to_string(), messages);
-        }
-
-        return (
-            //Send 422 response Unprocessable Entity
-            StatusCode::UNPROCESSABLE_ENTITY,
-            Json(ApiResponse{
-                status: false,
-                message: "Failed to validate".to_string(),
-                data: Some(json!(field_errors))
-            }),
-        );
-    }
-
+    Extension(mailer): Extension<SharedMailer>,
+    ValidatedJson(payload): ValidatedJson<RegisterRequest>
+) -> ApiResult {
     //Hash password with Bcrypt
-    let password = match hash(payload.password, 10) {
-        Ok(hashed) => hashed,
-        Err(_) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ApiResponse::error(
-                    "Failed to encrypt the password",
-                )),
-            );
-        }
-    };
+    let password = hash(&payload.password, 10)
+        .map_err(|_| AppError::Internal("Failed to encrypt the password".to_string()))?;
 
 
     //Insert user's data to database
     let user_id = Uuid::new_v4();
 
-    let user_role = match payload.role {
-        Some(RegRole::OWNER) => "OWNER",
-        _ => "MEMBER",
-    };
+    //Everyone registers as MEMBER, owners are approved by an admin
+    let user_role = "MEMBER";
 
-    let role_id = match sqlx::query_scalar!(
+    let role_id: Uuid = sqlx::query_scalar!(
         r#"
         SELECT id AS "id: Uuid"
         FROM Roles
@@ -81,90 +84,42 @@ pub async fn register(
         user_role
     )
     .fetch_one(&db)
-    .await 
-    {
-        Ok(role_id) => role_id,
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ApiResponse::error(
-                    e.to_string().as_ref()
-                ))
-            );
-        } 
-    };
+    .await?;
 
-    let result = sqlx::query!(
-        "INSERT INTO Users (id, name, email, password, role_id) VALUES (?, ?, ?, ?, ?)",
-        user_id,
-        payload.name,
-        payload.email,
-        role_id,
-        password
+    let token = register_user_tx(&db, user_id, &payload, &password, role_id)
+        .await
+        .or_conflict("Email has been registered")?;
+
+    //The account can login once the email is verified
+    send_verification_email(mailer, &payload.name, &payload.email, &token);
+
+    //Get user data by user id
+    let user = sqlx::query!(
+        r#"
+        SELECT id AS "id: Uuid", name, email, role_id AS "role_id: Uuid", created_at, updated_at
+        FROM Users
+        WHERE id = ?
+        "#,
+        user_id
     )
-    .execute(&db)
-    .await;
-
-    match result {
-        Ok(_result) => {
-            //Get user data by user id
-            let user = sqlx::query!(
-                r#"
-                SELECT id AS "id: Uuid", name, email, role_id AS "role_id: Uuid", created_at, updated_at
-                FROM Users
-                WHERE id = ?
-                "#,
-                user_id
-            )
-            .fetch_one(&db)
-            .await;
-            
-            match user {
-                Ok(user) => {
-                    let response = RegisterResponse {
-                        id: user.id,
-                        name: user.name,
-                        email: user.email,
-                        role_id: user.role_id.expect("User role not found"),
-                        created_at: user.created_at,
-                        updated_at: user.updated_at,
-                    };
-
-                    (
-                        //Send 201 response Created
-                        StatusCode::CREATED,
-                        Json(ApiResponse::success(
-                            "Register success", 
-                            json!(response)    
-                        ))
-                    )
-                }
-
-                Err(_) => (
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    Json(ApiResponse::error(
-                        "Failed to fetch user's data"
-                    ))
-                )
-            }
-        }
-
-        Err(e) => {
-            if e.to_string().contains("Duplicate entry") {
-                (
-                    StatusCode::CONFLICT,
-                    Json(ApiResponse::error(
-                        "Email has been registered"
-                    )),
-                )
-            } else {
-                (
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    Json(ApiResponse::error(
-                        e.to_string().as_str(),
-                    ))
-                )
-            }
-        }
-    }
+    .fetch_one(&db)
+    .await?;
+
+    let response = RegisterResponse {
+        id: user.id,
+        name: user.name,
+        email: user.email,
+        role_id: user.role_id.expect("User role not found"),
+        created_at: user.created_at,
+        updated_at: user.updated_at,
+    };
+
+    Ok((
+        //Send 201 response Created
+        StatusCode::CREATED,
+        Json(ApiResponse::success(
+            "Register success, please check your email to verify your account", 
+            json!(response)    
+        ))
+    ))
 }
\ No newline at end of file
@@ -0,0 +1,413 @@
+use std::collections::HashMap;
+
+use axum::{
+    Extension,
+    Json,
+    http::StatusCode,
+    extract::Path,
+};
+
+use sqlx::MySqlPool;
+use serde_json::json;
+
+use uuid::Uuid;
+
+// Import claims from utils
+use crate::utils::jwt::Claims;
+
+// Import role models
+use crate::models::role::{
+    Role,
+    Permission,
+    RolePermissionPath,
+};
+
+// Import role schema
+use crate::schemas::role_schema::{
+    RoleNewRequest,
+    RolePermissionRequest,
+    RoleResponse,
+    is_system_role,
+};
+
+// Import API Response
+use crate::utils::response::ApiResponse;
+
+// Import app error
+use crate::utils::error::{
+    ApiResult,
+    AppError,
+    DbResultExt,
+};
+
+// Import validated JSON extractor
+use crate::utils::validated_json::ValidatedJson;
+
+// Import audit and token helpers
+use crate::utils::{
+    audit::record_audit,
+    token_version::bump_role_token_version,
+};
+
+// Helper to get a role with its permissions
+async fn find_role(
+    db: &MySqlPool,
+    role_id: Uuid,
+) -> Result<RoleResponse, sqlx::Error> {
+    let role = sqlx::query_as!(
+        Role,
+        r#"SELECT id AS "id: Uuid", name FROM Roles WHERE id = ?"#,
+        role_id
+    )
+    .fetch_one(db)
+    .await?;
+
+    let permissions = sqlx::query_as!(
+        Permission,
+        r#"
+        SELECT p.id AS "id: Uuid", p.name
+        FROM Role_Permissions rp
+        JOIN Permissions p ON p.id = rp.permission_id
+        WHERE rp.role_id = ?
+        ORDER BY p.name ASC
+        "#,
+        role_id
+    )
+    .fetch_all(db)
+    .await?;
+
+    Ok(RoleResponse {
+        id: role.id,
+        is_system: is_system_role(&role.name),
+        name: role.name,
+        permissions,
+    })
+}
+
+// Helper to create a role and record the audit log in one transaction
+async fn create_role_tx(
+    db: &MySqlPool,
+    actor_id: Uuid,
+    role_id: Uuid,
+    name: &str,
+) -> Result<(), sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    sqlx::query!(
+        "INSERT INTO Roles (id, name) VALUES (?, ?)",
+        role_id,
+        name
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    record_audit(&mut *tx, Some(actor_id), "role.create", "role", Some(role_id), json!({ "name": name })).await?;
+
+    tx.commit().await
+}
+
+// Helper to delete a role and record the audit log in one transaction
+async fn delete_role_tx(
+    db: &MySqlPool,
+    actor_id: Uuid,
+    role: &RoleResponse,
+) -> Result<(), sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    sqlx::query!(
+        "DELETE FROM Roles WHERE id = ?",
+        role.id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    let permissions = role.permissions
+        .iter()
+        .map(|p| p.name.as_str())
+        .collect::<Vec<&str>>();
+
+    record_audit(
+        &mut *tx,
+        Some(actor_id),
+        "role.delete",
+        "role",
+        Some(role.id),
+        json!({ "name": role.name, "permissions": permissions }),
+    )
+    .await?;
+
+    tx.commit().await
+}
+
+// Helper to grant or revoke a permission and record the audit log in one transaction,
+// returns false when there was nothing to change
+async fn change_role_permission_tx(
+    db: &MySqlPool,
+    actor_id: Uuid,
+    role: &RoleResponse,
+    permission: &Permission,
+    grant: bool,
+) -> Result<bool, sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    let result = if grant {
+        sqlx::query!(
+            "INSERT IGNORE INTO Role_Permissions (role_id, permission_id) VALUES (?, ?)",
+            role.id,
+            permission.id
+        )
+        .execute(&mut *tx)
+        .await?
+    } else {
+        sqlx::query!(
+            "DELETE FROM Role_Permissions WHERE role_id = ? AND permission_id = ?",
+            role.id,
+            permission.id
+        )
+        .execute(&mut *tx)
+        .await?
+    };
+
+    if result.rows_affected() == 0 {
+        return Ok(false);
+    }
+
+    // Tokens of the users with the role still carry the old permissions
+    bump_role_token_version(&mut *tx, role.id).await?;
+
+    let action = if grant { "role.grant_permission" } else { "role.revoke_permission" };
+
+    record_audit(
+        &mut *tx,
+        Some(actor_id),
+        action,
+        "role",
+        Some(role.id),
+        json!({ "role": role.name, "permission": permission.name }),
+    )
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(true)
+}
+
+// Helper to get a permission by id
+async fn find_permission(
+    db: &MySqlPool,
+    permission_id: Uuid,
+) -> Result<Permission, sqlx::Error> {
+    sqlx::query_as!(
+        Permission,
+        r#"SELECT id AS "id: Uuid", name FROM Permissions WHERE id = ?"#,
+        permission_id
+    )
+    .fetch_one(db)
+    .await
+}
+
+// Handler to get all roles with their permissions
+pub async fn get_all_roles(
+    Extension(db): Extension<MySqlPool>,
+) -> ApiResult {
+    let roles = sqlx::query_as!(
+        Role,
+        r#"SELECT id AS "id: Uuid", name FROM Roles ORDER BY name ASC"#
+    )
+    .fetch_all(&db)
+    .await?;
+
+    let grants = sqlx::query!(
+        r#"
+        SELECT rp.role_id AS "role_id: Uuid", p.id AS "id: Uuid", p.name
+        FROM Role_Permissions rp
+        JOIN Permissions p ON p.id = rp.permission_id
+        ORDER BY p.name ASC
+        "#
+    )
+    .fetch_all(&db)
+    .await?;
+
+    let mut permissions: HashMap<Uuid, Vec<Permission>> = HashMap::new();
+
+    for grant in grants {
+        permissions
+            .entry(grant.role_id)
+            .or_default()
+            .push(Permission { id: grant.id, name: grant.name });
+    }
+
+    let response = roles
+        .into_iter()
+        .map(|role| RoleResponse {
+            id: role.id,
+            is_system: is_system_role(&role.name),
+            permissions: permissions.remove(&role.id).unwrap_or_default(),
+            name: role.name,
+        })
+        .collect::<Vec<RoleResponse>>();
+
+    Ok((
+        // Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "Roles List",
+            json!(response)))
+    ))
+}
+
+// Handler to get role by id
+pub async fn get_role_by_id(
+    Extension(db): Extension<MySqlPool>,
+    Path(role_id): Path<Uuid>,
+) -> ApiResult {
+    let role = find_role(&db, role_id)
+        .await
+        .or_not_found("Role with provided id is not found")?;
+
+    Ok((
+        // Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "Role Details",
+            json!(role)))
+    ))
+}
+
+// Handler to create new role
+pub async fn create_role(
+    Extension(db): Extension<MySqlPool>,
+    Extension(claims): Extension<Claims>,
+    ValidatedJson(payload): ValidatedJson<RoleNewRequest>,
+) -> ApiResult {
+    // Check the role name is not taken
+    let role_exist = sqlx::query!(
+        "SELECT id FROM Roles WHERE name = ?",
+        payload.name
+    )
+    .fetch_optional(&db)
+    .await?;
+
+    if role_exist.is_some() {
+        return Err(AppError::Conflict("Role with provided name already exists".to_string()));
+    }
+
+    let role_id = Uuid::new_v4();
+
+    create_role_tx(&db, claims.sub, role_id, &payload.name)
+        .await
+        .or_conflict("Role with provided name already exists")?;
+
+    // Get newly created role
+    let role = find_role(&db, role_id).await?;
+
+    Ok((
+        // Send 201 response Created
+        StatusCode::CREATED,
+        Json(ApiResponse::success(
+            "Role created successfully",
+            json!(role)))
+    ))
+}
+
+// Handler to delete a role, system roles and roles still assigned to users are kept
+pub async fn delete_role(
+    Extension(db): Extension<MySqlPool>,
+    Extension(claims): Extension<Claims>,
+    Path(role_id): Path<Uuid>,
+) -> ApiResult {
+    let role = find_role(&db, role_id)
+        .await
+        .or_not_found("Role with provided id is not found")?;
+
+    if role.is_system {
+        return Err(AppError::Conflict("System role cannot be deleted".to_string()));
+    }
+
+    // Users keep a reference to their role
+    let user_count = sqlx::query_scalar!(
+        r#"SELECT COUNT(*) AS "count!: i64" FROM Users WHERE role_id = ?"#,
+        role.id
+    )
+    .fetch_one(&db)
+    .await?;
+
+    if user_count > 0 {
+        return Err(AppError::Conflict("Role is still assigned to users".to_string()));
+    }
+
+    delete_role_tx(&db, claims.sub, &role)
+        .await
+        .or_referenced("Role is still assigned to users")?;
+
+    Ok((
+        // Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "Role deleted successfully",
+            json!(null)))
+    ))
+}
+
+// Handler to grant a permission to a role
+pub async fn grant_role_permission(
+    Extension(db): Extension<MySqlPool>,
+    Extension(claims): Extension<Claims>,
+    Path(role_id): Path<Uuid>,
+    ValidatedJson(payload): ValidatedJson<RolePermissionRequest>,
+) -> ApiResult {
+    change_role_permission(&db, &claims, role_id, payload.permission_id, true).await
+}
+
+// Handler to revoke a permission from a role
+pub async fn revoke_role_permission(
+    Extension(db): Extension<MySqlPool>,
+    Extension(claims): Extension<Claims>,
+    Path(path): Path<RolePermissionPath>,
+) -> ApiResult {
+    change_role_permission(&db, &claims, path.role_id, path.permission_id, false).await
+}
+
+// Shared body of the grant and revoke handlers
+async fn change_role_permission(
+    db: &MySqlPool,
+    claims: &Claims,
+    role_id: Uuid,
+    permission_id: Uuid,
+    grant: bool,
+) -> ApiResult {
+    let role = find_role(db, role_id)
+        .await
+        .or_not_found("Role with provided id is not found")?;
+
+    let permission = find_permission(db, permission_id)
+        .await
+        .or_not_found("Permission with provided id is not found")?;
+
+    let changed = change_role_permission_tx(db, claims.sub, &role, &permission, grant).await?;
+
+    if !changed && grant {
+        return Err(AppError::Conflict("Role already has this permission".to_string()));
+    }
+
+    if !changed {
+        return Err(AppError::NotFound("Role does not have this permission".to_string()));
+    }
+
+    let message = if grant {
+        "Permission granted successfully"
+    } else {
+        "Permission revoked successfully"
+    };
+
+    // Get new role data
+    let role = find_role(db, role.id).await?;
+
+    Ok((
+        // Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            message,
+            json!(role)))
+    ))
+}
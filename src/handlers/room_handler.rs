@@ -1,5 +1,3 @@
-use std::collections::HashMap;
-
 use axum::{
     Extension,
     Json,
@@ -8,13 +6,9 @@ use axum::{
 };
 
 use sqlx::MySqlPool;
-use serde_json::{
-    json,
-    Value,
-};
+use serde_json::json;
 
 use uuid::Uuid;
-use validator::Validate;
 
 // Import claims from utils
 use crate::utils::jwt::Claims;
@@ -32,21 +26,35 @@ use crate::schemas::room_schema::{
     RoomUpdateRequest,
     RoomUpdateResponse,
     RoomStatus,
+    DEFAULT_CURRENCY,
 };
 
 // Import API Response
 use crate::utils::response::ApiResponse;
 
+// Import app error
+use crate::utils::error::{
+    ApiResult,
+    AppError,
+    DbResultExt,
+};
+
+// Import validated JSON extractor
+use crate::utils::validated_json::ValidatedJson;
+
+// Import room vacancy helper
+use crate::utils::room_vacancy::has_open_booking;
+
 // Handler to create new room
 pub async fn create_room(
     Extension(db): Extension<MySqlPool>,
     Path(kost_id): Path<Uuid>,
     Extension(claims): Extension<Claims>,
-    Json(payload): Json<RoomNewRequest>,
-) -> (StatusCode, Json<ApiResponse<Value>>) {
+    ValidatedJson(payload): ValidatedJson<RoomNewRequest>,
+) -> ApiResult {
 
     // Guard, so only kost owner can access and modify
-    let kost = match sqlx::query!(
+    let kost = sqlx::query!(
         r#"
         SELECT id AS "id: Uuid", user_id AS "user_id: Uuid"
         FROM Kosts
@@ -56,123 +64,62 @@ pub async fn create_room(
     )
     .fetch_one(&db)
     .await 
-    {
-        Ok(kost) => kost,
-        Err(e) => {
-            eprintln!("Database error: {}", e);
-            return (
-                // Send 500 response Internal Server Error
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ApiResponse::error(
-                    e.to_string().as_ref(),
-                ))
-            )
-        }
-    };
+    .or_not_found("Kost with provided id is not found")?;
 
     if kost.user_id != claims.sub {
-        return (
-            // Send 401 Unauthorized
-            StatusCode::UNAUTHORIZED,
-            Json(ApiResponse::error(
-                "Only owner can access and modify this kost"
-            ))
-        );
-    }
-
-    // Validate the request
-    if let Err(e) = payload.validate() {
-        let mut field_errors: HashMap<String, Vec<String>> = HashMap::new();
-
-        for (field, errors) in e.field_errors() {
-            let messages = errors
-                .iter()
-                .filter_map(|e| e.message.as_ref())
-                .map(|m | m.to_string())
-                .collect::<Vec<String>>();
-
-            field_errors.insert(field.to_string(), messages);
-        } 
-
-        return (
-            // Send 402 response Unprocessable Entity
-            StatusCode::UNPROCESSABLE_ENTITY,
-            Json(ApiResponse {
-                status: false,
-                message: "Failed to validate the request".to_string(),
-                data: Some(json!(field_errors))
-            })
-        );
+        return Err(AppError::Unauthorized("Only owner can access and modify this kost".to_string()));
     }
 
     // Insert new room to database
     let room_id = Uuid::new_v4();
+    let currency = payload.currency.as_deref().unwrap_or(DEFAULT_CURRENCY);
 
-    let result = sqlx::query!(
-        "INSERT INTO Rooms (id, kost_id, room_number, room_vacancy) VALUES (?, ?, ?, ?)",
+    sqlx::query!(
+        "INSERT INTO Rooms (id, kost_id, room_number, room_vacancy, monthly_rent, deposit, currency) VALUES (?, ?, ?, ?, ?, ?, ?)",
         room_id,
         kost_id,
         payload.room_number,
-        payload.room_vacancy
+        payload.room_vacancy,
+        payload.monthly_rent,
+        payload.deposit,
+        currency
     )
     .execute(&db)
-    .await;
-
-    match result {
-        Ok(_result) => {
-            // Get newly created room
-            let room = sqlx::query_as!(
-                Room,
-                r#"
-                SELECT id AS "id: Uuid", kost_id AS "kost_id: Uuid", room_number AS "room_number: u32", room_vacancy AS "room_vacancy: RoomStatus", created_at, updated_at
-                FROM Rooms
-                WHERE id = ?
-                "#,
-                room_id
-            )
-            .fetch_one(&db)
-            .await;
-
-            match room {
-                Ok(room) => {
-                    let response = RoomNewResponse {
-                        id: room.id,
-                        kost_id: room.kost_id,
-                        room_number: room.room_number,
-                        room_vacancy: room.room_vacancy,
-                        created_at: room.created_at,
-                        updated_at: room.updated_at,
-                    };
-
-                    (
-                        // Send 200 response Ok
-                        StatusCode::OK,
-                        Json(ApiResponse::success(
-                            "Room created successfully", 
-                            json!(response))),
-                    )
-                },
-                Err(_) => (
-                    // Send 500 response Internal Server Error
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    Json(ApiResponse::error(
-                        "Failed to get new kost data"
-                    ))
-                )
-            }
-        },
-        Err(e) => {
-            eprintln!("Database error: {}", e);
-            (
-                // Send 500 response Internal Server Error
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ApiResponse::error(
-                    e.to_string().as_ref()
-                ))
-            )
-        }
-    }
+    .await
+    .or_conflict("Room number already exist")?;
 
+    // Get newly created room
+    let room = sqlx::query_as!(
+        Room,
+        r#"
+        SELECT id AS "id: Uuid", kost_id AS "kost_id: Uuid", room_number AS "room_number: u32", room_vacancy AS "room_vacancy: RoomStatus", monthly_rent, deposit, currency, created_at, updated_at
+        FROM Rooms
+        WHERE id = ?
+        "#,
+        room_id
+    )
+    .fetch_one(&db)
+    .await?;
+
+    let response = RoomNewResponse {
+        id: room.id,
+        kost_id: room.kost_id,
+        room_number: room.room_number,
+        room_vacancy: room.room_vacancy,
+        monthly_rent: room.monthly_rent,
+        deposit: room.deposit,
+        currency: room.currency,
+        created_at: room.created_at,
+        updated_at: room.updated_at,
+    };
+
+    Ok((
+        // Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "Room created successfully", 
+            json!(response))),
+    ))
 }
 
 // Handler to get all room data
@@ -180,45 +127,24 @@ pub async fn get_all_rooms(
     Extension(db): Extension<MySqlPool>,
     Path(kost_id): Path<Uuid>,
     Extension(claims): Extension<Claims>
-) -> (StatusCode, Json<ApiResponse<Value>>) {
+) -> ApiResult {
     // Get all rooms data
-    if claims.role == "ADMIN" {
-        let rooms = match sqlx::query_as!(
+    let rooms = if claims.role == "ADMIN" {
+        sqlx::query_as!(
             Room,
             r#"
-            SELECT id AS "id: Uuid", kost_id AS "kost_id: Uuid", room_number AS "room_number: u32", room_vacancy AS "room_vacancy: RoomStatus", created_at, updated_at
+            SELECT id AS "id: Uuid", kost_id AS "kost_id: Uuid", room_number AS "room_number: u32", room_vacancy AS "room_vacancy: RoomStatus", monthly_rent, deposit, currency, created_at, updated_at
             FROM Rooms
             ORDER BY room_number DESC
             "#,
         )
         .fetch_all(&db)
-        .await
-        {
-            Ok(rooms) => rooms,
-            Err(e) => {
-                eprintln!("Database error: {}", e);
-                return (
-                    // Send 500 response Internal Server Error
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    Json(ApiResponse::error(
-                        e.to_string().as_ref(),
-                    ))
-                );
-            }
-        };
-
-        (
-            // Send 200 response OK
-            StatusCode::OK,
-            Json(ApiResponse::success(
-                "Rooms List", 
-                json!(rooms)))
-        )    
+        .await?
     } else {
-        let rooms = match sqlx::query_as!(
+        sqlx::query_as!(
             Room,
             r#"
-            SELECT id AS "id: Uuid", kost_id AS "kost_id: Uuid", room_number AS "room_number: u32", room_vacancy AS "room_vacancy: RoomStatus", created_at, updated_at
+            SELECT id AS "id: Uuid", kost_id AS "kost_id: Uuid", room_number AS "room_number: u32", room_vacancy AS "room_vacancy: RoomStatus", monthly_rent, deposit, currency, created_at, updated_at
             FROM Rooms
             WHERE kost_id = ?
             ORDER BY room_number ASC
@@ -226,29 +152,16 @@ pub async fn get_all_rooms(
             kost_id,
         )
         .fetch_all(&db)
-        .await
-        {
-            Ok(rooms) => rooms,
-            Err(e) => {
-                eprintln!("Database Error: {}", e);
-                return (
-                    // Send 500 response Internal Server Error
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    Json(ApiResponse::error(
-                        e.to_string().as_ref(),
-                    ))
-                );
-            }
-        };
-
-        (
-            // Send 200 response Ok
-            StatusCode::OK,
-            Json(ApiResponse::success(
-                "Rooms List", 
-                json!(rooms)))
-        )
-    }
+        .await?
+    };
+
+    Ok((
+        // Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "Rooms List", 
+            json!(rooms)))
+    ))
 }
 
 // Handler to get room by id 
@@ -256,12 +169,12 @@ pub async fn get_room_by_id(
     Extension(db): Extension<MySqlPool>,
     Extension(claims): Extension<Claims>,
     Path(path): Path<RoomPath>
-) -> (StatusCode, Json<ApiResponse<Value>>) {
+) -> ApiResult {
     let kost_id = path.kost_id;
     let room_id = path.room_id;
 
     // Guard
-    let kost = match sqlx::query!(
+    let kost = sqlx::query!(
         r#"
         SELECT id AS "id: Uuid", user_id AS "user_id: Uuid"
         FROM Kosts
@@ -271,34 +184,16 @@ pub async fn get_room_by_id(
     )
     .fetch_one(&db)
     .await 
-    {
-        Ok(kost) => kost,
-        Err(e) => {
-            eprintln!("Database error: {}", e);
-            return (
-                // Send 500 response Internal Server Error
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ApiResponse::error(
-                    e.to_string().as_ref(),
-                ))
-            );
-        }
-    };
+    .or_not_found("Kost with provided id is not found")?;
 
     if kost.user_id != claims.sub {
-        return (
-            // Send 401 response Unauthorized
-            StatusCode::UNAUTHORIZED,
-            Json(ApiResponse::error(
-                "Only owner can access this"
-            ))
-        );
+        return Err(AppError::Unauthorized("Only owner can access this".to_string()));
     }
 
     // Get room data by id
-    let room = match sqlx::query!(
+    let room = sqlx::query!(
         r#"
-        SELECT id AS "id: Uuid", kost_id AS "kost_id: Uuid", room_number AS "room_number: u32", room_vacancy AS "room_vacancy: RoomStatus", created_at, updated_at
+        SELECT id AS "id: Uuid", kost_id AS "kost_id: Uuid", room_number AS "room_number: u32", room_vacancy AS "room_vacancy: RoomStatus", monthly_rent, deposit, currency, created_at, updated_at
         FROM Rooms
         WHERE id = ? AND kost_id = ?
         "#,
@@ -307,45 +202,27 @@ pub async fn get_room_by_id(
     ) 
     .fetch_one(&db)
     .await
-    {
-        Ok(room) => room,
-        Err(sqlx::Error::RowNotFound) => {
-            return (
-                // Send 404 response Not Found
-                StatusCode::NOT_FOUND,
-                Json(ApiResponse::error(
-                    "Room with provided id is not found"
-                ))
-            );
-        },
-        Err(e) => {
-            eprintln!("Database error: {}",e);
-            return (
-                // Send 500 response Internal Server Error
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ApiResponse::error(
-                    e.to_string().as_ref(),
-                ))
-            );
-        }
-    };
+    .or_not_found("Room with provided id is not found")?;
 
     let response = RoomNewResponse {
         id: room.id,
         kost_id: room.kost_id,
         room_number: room.room_number,
         room_vacancy: room.room_vacancy,
+        monthly_rent: room.monthly_rent,
+        deposit: room.deposit,
+        currency: room.currency,
         created_at: room.created_at,
         updated_at: room.updated_at
     };
 
-    (
+    Ok((
         // Send 200 response OK
         StatusCode::OK,
         Json(ApiResponse::success(
             "Room Details", 
             json!(response)))
-    )
+    ))
 }
 
 // Handler to update room
@@ -353,12 +230,12 @@ pub async fn update_room(
     Extension(db): Extension<MySqlPool>,
     Extension(claims): Extension<Claims>,
     Path(path): Path<RoomPath>,
-    Json(payload): Json<RoomUpdateRequest>,
-) -> (StatusCode, Json<ApiResponse<Value>>) {
+    ValidatedJson(payload): ValidatedJson<RoomUpdateRequest>,
+) -> ApiResult {
     // Guard for kost and room
     let (kost_id, room_id) = (path.kost_id, path.room_id);
 
-    let _kost = match sqlx::query!(
+    sqlx::query!(
         r#"
         SELECT id AS "id: Uuid", user_id AS "user_id: Uuid"
         FROM Kosts
@@ -369,32 +246,11 @@ pub async fn update_room(
     )
     .fetch_one(&db)
     .await
-    {
-        Ok(kost) => kost,
-        Err(sqlx::Error::RowNotFound) => {
-            return (
-                // Return 404 response Not Found
-                StatusCode::NOT_FOUND,
-                Json(ApiResponse::error(
-                    "Kost with provided id is not found",
-                ))
-            );
-        },
-        Err(e) => {
-            eprintln!("Database error: {}", e);
-            return (
-                // Send 500 response Internal Server Error
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ApiResponse::error(
-                    e.to_string().as_ref(),
-                ))
-            );
-        }
-    };
+    .or_not_found("Kost with provided id is not found")?;
 
-    let _room = match sqlx::query!(
+    sqlx::query!(
         r#"
-        SELECT id as "id: Uuid", kost_id AS "kost_id: Uuid", room_number AS "room_number: u32", room_vacancy AS "room_vacancy: RoomStatus", created_at, updated_at
+        SELECT id as "id: Uuid", kost_id AS "kost_id: Uuid", room_number AS "room_number: u32", room_vacancy AS "room_vacancy: RoomStatus", monthly_rent, deposit, currency, created_at, updated_at
         FROM Rooms
         WHERE id = ? AND kost_id = ?
         "#,
@@ -403,53 +259,36 @@ pub async fn update_room(
     ) 
     .fetch_one(&db)
     .await
-    {
-        Ok(room) => room,
-        Err(sqlx::Error::RowNotFound) => {
-            return (
-                // Send 404 response Not Found
-                StatusCode::NOT_FOUND,
-                Json(ApiResponse::error(
-                    "Room with provided id is not found"
-                ))
-            );
-        },
-        Err(e) => {
-            eprintln!("Database error: {}", e);
-            return (
-                // Return 500 response Internal Server Error
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ApiResponse::error(
-                    e.to_string().as_ref(),
-                ))
-            );
-        }
-    };
+    .or_not_found("Room with provided id is not found")?;
+
+    // Room with an open booking cannot be marked as available
+    if matches!(payload.room_vacancy, RoomStatus::AVAILABLE) && has_open_booking(&db, room_id).await? {
+        return Err(AppError::Conflict("Room still has an open booking".to_string()));
+    }
 
-    // Update kost data
-    let result = sqlx::query!(
+    // Update room data, price fields that are left out keep the current value
+    sqlx::query!(
         "
         UPDATE Rooms
-        SET room_number = ?, room_vacancy = ?
+        SET
+            room_number = ?,
+            room_vacancy = ?,
+            monthly_rent = COALESCE(?, monthly_rent),
+            deposit = IF(?, ?, deposit),
+            currency = COALESCE(?, currency)
         WHERE id = ?
         ",
         payload.room_number,
         payload.room_vacancy,
+        payload.monthly_rent,
+        payload.deposit.is_some(),
+        payload.deposit.flatten(),
+        payload.currency,
         room_id
     )
     .execute(&db)
-    .await;
-
-    if let Err(e) = result {
-        eprintln!("Database error: {}", e);
-        return (
-            // Send 500 response Internal Server Error
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ApiResponse::error(
-                e.to_string().as_ref(),
-            ))
-        );
-    }
+    .await
+    .or_conflict("Room number already exist")?;
 
     // Get new room data
     let updated_room = sqlx::query!(
@@ -459,6 +298,9 @@ pub async fn update_room(
             kost_id AS "kost_id: Uuid",
             room_number AS "room_number: u32",
             room_vacancy AS "room_vacancy: RoomStatus",
+            monthly_rent,
+            deposit,
+            currency,
             created_at,
             updated_at
         FROM Rooms
@@ -467,38 +309,28 @@ pub async fn update_room(
         room_id
     )
     .fetch_one(&db)
-    .await;
-
-    match updated_room {
-        Ok(updated_room) => {
-            let response = RoomUpdateResponse {
-                id: updated_room.id,
-                kost_id: updated_room.kost_id,
-                room_number: updated_room.room_number,
-                room_vacancy: updated_room.room_vacancy,
-                created_at: updated_room.created_at,
-                updated_at: updated_room.updated_at
-            };
-
-            return (
-                // Send 200 response Ok
-                StatusCode::OK,
-                Json(ApiResponse::success(
-                    "Room updated successfully", 
-                    json!(response),
-                ))
-            );
-        },
-        Err(_) => {
-            return (
-                // Send 500 response Internal Server Error
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ApiResponse::error(
-                    "Server Error",
-                ))
-            );
-        }
-    }
+    .await?;
+
+    let response = RoomUpdateResponse {
+        id: updated_room.id,
+        kost_id: updated_room.kost_id,
+        room_number: updated_room.room_number,
+        room_vacancy: updated_room.room_vacancy,
+        monthly_rent: updated_room.monthly_rent,
+        deposit: updated_room.deposit,
+        currency: updated_room.currency,
+        created_at: updated_room.created_at,
+        updated_at: updated_room.updated_at
+    };
+
+    Ok((
+        // Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "Room updated successfully", 
+            json!(response),
+        ))
+    ))
 }
 
 // Handler to delete room
@@ -506,11 +338,11 @@ pub async fn delete_room(
     Extension(db): Extension<MySqlPool>,
     Extension(claims): Extension<Claims>,
     Path(path): Path<RoomPath>,
-) -> (StatusCode, Json<ApiResponse<Value>>) {
+) -> ApiResult {
     // Check the kost and room exist
     let (kost_id, room_id) = (path.kost_id, path.room_id);
 
-    let kost = match sqlx::query!(
+    sqlx::query!(
         r#"
         SELECT id AS "id: Uuid", user_id AS "user_id: Uuid"
         FROM Kosts
@@ -521,30 +353,9 @@ pub async fn delete_room(
     )
     .fetch_one(&db)
     .await
-    {
-        Ok(kost) => kost,
-        Err(sqlx::Error::RowNotFound) => {
-            return (
-                // Send 404 response Not Found
-                StatusCode::NOT_FOUND,
-                Json(ApiResponse::error(
-                    "Kost with provided Id is not found",
-                ))
-            );
-        },
-        Err(e) => {
-            eprintln!("Database error: {}", e);
-            return (
-                // Send 500 response Internal Server Error
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ApiResponse::error(
-                    e.to_string().as_ref(),
-                ))
-            );
-        }
-    };
+    .or_not_found("Kost with provided Id is not found")?;
 
-    let room = match sqlx::query!(
+    let room = sqlx::query!(
         r#"
         SELECT id AS "id: Uuid", kost_id AS "kost_id: Uuid"
         FROM Rooms
@@ -555,57 +366,23 @@ pub async fn delete_room(
     ) 
     .fetch_one(&db)
     .await
-    {
-        Ok(room) => room,
-        Err(sqlx::Error::RowNotFound) => {
-            return (
-                // Send 404 response Not Found
-                StatusCode::NOT_FOUND,
-                Json(ApiResponse::error(
-                    "Room with provided id is not found",
-                ))
-            );
-        },
-        Err(e) => {
-            eprintln!("Database error: {}", e);
-            return (
-                // Send 500 response Internal Server Error
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ApiResponse::error(
-                    e.to_string().as_ref(),
-                ))
-            );
-        }
-    };
+    .or_not_found("Room with provided id is not found")?;
 
-    let result = sqlx::query!(
+    sqlx::query!(
         "
         DELETE FROM Rooms WHERE id = ?
         ",
         room.id
     )
     .execute(&db)
-    .await;
-
-    match result {
-        Ok(_) => {
-            return (
-                // Send 200 response Ok
-                StatusCode::OK,
-                Json(ApiResponse::success(
-                    "Room deleted successfully", 
-                    json!(null)))
-            );
-        },
-        Err(e) => {
-            eprintln!("Database Error: {}", e);
-            return (
-                // Send 500 response Internal Server Error
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ApiResponse::error(
-                    e.to_string().as_ref(),
-                ))
-            );
-        }
-    }
+    .await
+    .or_referenced("Room has bookings with invoices, cancel the bookings instead of deleting the room")?;
+
+    Ok((
+        // Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "Room deleted successfully", 
+            json!(null)))
+    ))
 }
\ No newline at end of file
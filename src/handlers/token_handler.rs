@@ -0,0 +1,192 @@
+use axum::{
+    Extension,
+    Json,
+    http::StatusCode,
+};
+
+use sqlx::MySqlPool;
+use serde_json::json;
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+// Import token schema
+use crate::schemas::token_schema::{
+    RefreshTokenRequest,
+    RefreshTokenResponse,
+};
+
+// Import API Response
+use crate::utils::response::ApiResponse;
+
+// Import app error
+use crate::utils::error::{
+    ApiResult,
+    AppError,
+};
+
+// Import validated JSON extractor
+use crate::utils::validated_json::ValidatedJson;
+
+// Import app config
+use crate::config::app_config;
+
+// Import token helpers
+use crate::utils::{
+    jwt::{generate_token, Claims},
+    refresh_token::{issue_refresh_token, user_access},
+    secure_token::hash_token,
+};
+
+// Result of a refresh token rotation
+enum RotateOutcome {
+    Rotated { user_id: Uuid, refresh_token: String },
+    Invalid,
+    Expired,
+    Reused,
+}
+
+// Helper to swap a refresh token for a new one in one transaction.
+// A token that was already swapped means it leaked, so every token of the user is revoked
+async fn rotate_refresh_token_tx(
+    db: &MySqlPool,
+    refresh_token: &str,
+) -> Result<RotateOutcome, sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    let stored = match sqlx::query!(
+        r#"
+        SELECT id AS "id: Uuid", user_id AS "user_id: Uuid", expires_at, revoked_at
+        FROM Refresh_Tokens
+        WHERE token_hash = ?
+        FOR UPDATE
+        "#,
+        hash_token(refresh_token)
+    )
+    .fetch_optional(&mut *tx)
+    .await?
+    {
+        Some(stored) => stored,
+        None => return Ok(RotateOutcome::Invalid),
+    };
+
+    if stored.revoked_at.is_some() {
+        sqlx::query!(
+            "UPDATE Refresh_Tokens SET revoked_at = UTC_TIMESTAMP() WHERE user_id = ? AND revoked_at IS NULL",
+            stored.user_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        return Ok(RotateOutcome::Reused);
+    }
+
+    if stored.expires_at <= Utc::now().naive_utc() {
+        return Ok(RotateOutcome::Expired);
+    }
+
+    let (new_id, new_token) = issue_refresh_token(&mut *tx, stored.user_id).await?;
+
+    sqlx::query!(
+        "UPDATE Refresh_Tokens SET revoked_at = UTC_TIMESTAMP(), replaced_by = ? WHERE id = ?",
+        new_id,
+        stored.id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(RotateOutcome::Rotated { user_id: stored.user_id, refresh_token: new_token })
+}
+
+// Handler to get a new access token with a refresh token, the refresh token is replaced too
+pub async fn refresh_token(
+    Extension(db): Extension<MySqlPool>,
+    ValidatedJson(payload): ValidatedJson<RefreshTokenRequest>,
+) -> ApiResult {
+    let (user_id, refresh_token) = match rotate_refresh_token_tx(&db, &payload.refresh_token).await? {
+        RotateOutcome::Rotated { user_id, refresh_token } => (user_id, refresh_token),
+        RotateOutcome::Invalid | RotateOutcome::Reused => {
+            return Err(AppError::Unauthorized("Refresh token is not valid".to_string()));
+        },
+        RotateOutcome::Expired => {
+            return Err(AppError::Unauthorized("Refresh token has expired, please login again".to_string()));
+        },
+    };
+
+    // Role and permissions are read again, so changes are picked up on refresh
+    let (role, permissions, token_version) = user_access(&db, user_id).await?;
+
+    let token = generate_token(user_id, role, permissions, token_version)
+        .map_err(|e| {
+            eprintln!("JWT generation error: {}", e);
+            AppError::Internal("Failed to generate token".to_string())
+        })?;
+
+    let response = RefreshTokenResponse {
+        token,
+        refresh_token,
+        expires_in: app_config::get().access_token_minutes * 60,
+    };
+
+    Ok((
+        // Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "Token refreshed successfully",
+            json!(response)))
+    ))
+}
+
+// Helper to revoke the refresh token and the current access token in one transaction
+async fn logout_tx(
+    db: &MySqlPool,
+    claims: &Claims,
+    refresh_token: &str,
+) -> Result<(), sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    sqlx::query!(
+        "UPDATE Refresh_Tokens SET revoked_at = UTC_TIMESTAMP() WHERE token_hash = ? AND user_id = ? AND revoked_at IS NULL",
+        hash_token(refresh_token),
+        claims.sub
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    // Kept until the access token would have expired anyway
+    let expires_at = DateTime::<Utc>::from_timestamp(claims.exp as i64, 0)
+        .map(|exp| exp.naive_utc())
+        .unwrap_or_else(|| Utc::now().naive_utc());
+
+    sqlx::query!(
+        "INSERT IGNORE INTO Revoked_Tokens (jti, user_id, expires_at) VALUES (?, ?, ?)",
+        claims.jti,
+        claims.sub,
+        expires_at
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await
+}
+
+// Handler to logout, the refresh token and the access token used for the request stop working
+pub async fn logout(
+    Extension(db): Extension<MySqlPool>,
+    Extension(claims): Extension<Claims>,
+    ValidatedJson(payload): ValidatedJson<RefreshTokenRequest>,
+) -> ApiResult {
+    logout_tx(&db, &claims, &payload.refresh_token).await?;
+
+    Ok((
+        // Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "Logout success",
+            json!(null)))
+    ))
+}
@@ -1,5 +1,3 @@
-use std::collections::HashMap;
-
 use axum::{
     Extension,
     Json,
@@ -9,18 +7,15 @@ use axum::{
 
 use bcrypt::hash;
 use sqlx::MySqlPool;
-use serde_json::{
-    json, 
-    Value
-};
+use serde_json::json;
 use uuid::Uuid;
-use validator::Validate;
 
 //Import user schema
 use crate::schemas::user_schema::{
     UserNewRequest,
     UserNewResponse, 
     UserUpdateRequest,
+    UserRoleRequest,
 };
 
 // Import user models
@@ -29,91 +24,94 @@ use crate::models::user::User;
 //Import API response from utils
 use crate::utils::response::ApiResponse;
 
+// Import app error
+use crate::utils::error::{
+    ApiResult,
+    AppError,
+    DbResultExt,
+};
+
+// Import validated JSON extractor
+use crate::utils::validated_json::ValidatedJson;
+
+// Import claims from utils
+use crate::utils::jwt::Claims;
+
+// Import helpers
+use crate::utils::{
+    audit::record_audit,
+    token_version::bump_user_token_version,
+};
+
 // Import Role enum from reg schema
 use crate::schemas::register_schema::RegRole;
 
+// Import mailer and email verification helpers
+use crate::mailer::SharedMailer;
+use crate::utils::email_verification::{issue_verification_token, send_verification_email};
+
 //Handler to get all users data
 pub async fn index(
     Extension(db): Extension<MySqlPool>,
-) -> (StatusCode, Json<ApiResponse<Value>>) {
+) -> ApiResult {
     //Get all user data
-    let users = match sqlx::query_as!(
+    let users = sqlx::query_as!(
         User,
         r#"
-            SELECT id as "id: Uuid", name, email, role_id AS "role_id: Uuid", created_at, updated_at
+            SELECT id as "id: Uuid", name, email, email_verified_at, role_id AS "role_id: Uuid", created_at, updated_at
             FROM Users
             ORDER BY name ASC
         "#
     ) 
     .fetch_all(&db)
-    .await
-    {
-        Ok(users) => users,
-        Err(e) => {
-            eprintln!("Database error: {}", e);
-            return (
-                //Send 500 response Internal Server Error
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ApiResponse::error(
-                    "Failed to get user data"
-                )),
-            );
-        }
-    };
+    .await?;
 
-    (
+    Ok((
         StatusCode::OK,
         Json(ApiResponse::success(
             "User List", 
             json!(users)
         ))
+    ))
+}
+
+// Helper to insert a user and the verification token in one transaction, returns the token to email
+async fn create_user_tx(
+    db: &MySqlPool,
+    user_id: Uuid,
+    payload: &UserNewRequest,
+    password: &str,
+    role_id: Uuid,
+) -> Result<String, sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    sqlx::query!(
+        "INSERT INTO Users (id, name, email, password, role_id) VALUES (?, ?, ?, ?, ?)",
+        user_id,
+        payload.name,
+        payload.email,
+        password,
+        role_id
     )
+    .execute(&mut *tx)
+    .await?;
+
+    let token = issue_verification_token(&mut *tx, user_id).await?;
+
+    tx.commit().await?;
+
+    Ok(token)
 }
 
 //Handler to create new user
 pub async fn store(
     Extension(db): Extension<MySqlPool>,
-    Json(payload): Json<UserNewRequest>
-) -> (StatusCode, Json<ApiResponse<Value>>) {
-    //Validate request
-    if let Err(e) = payload.validate() {
-        let mut field_errors: HashMap<String, Vec<String>> = HashMap::new();
-
-        //Collect all the errors
-        for (field, errors) in e.field_errors() {
-            let messages = errors
-                .iter()
-                .filter_map(|e| e.message.as_ref())
-                .map(|m| m.to_string())
-                .collect::<Vec<String>>();
-
-            field_errors.insert(field.to_string(), messages);
-        }
-
-        return (
-            //Send 402 response Unprocessable Entity
-            StatusCode::UNPROCESSABLE_ENTITY,
-            Json(ApiResponse{
-                status: false,
-                message: "Failed to validate".to_string(),
-                data: Some(json!(field_errors))
-            })
-        );
-    }
-
+    Extension(mailer): Extension<SharedMailer>,
+    ValidatedJson(payload): ValidatedJson<UserNewRequest>
+) -> ApiResult {
     //Hash password with Bcrypt
-    let password = match hash(payload.password, 10) {
-        Ok(hashed) => hashed,
-        Err(_) => {
-            return (
-                //Send 500 response Internal Servel Error
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ApiResponse::error(
-                    "Failed to encrypt the password"
-                ))
-            );
-        }
-    };
+    let password = hash(&payload.password, 10)
+        .map_err(|_| AppError::Internal("Failed to encrypt the password".to_string()))?;
 
     //Insert new user data to database
     let new_user_id = Uuid::new_v4();
@@ -123,7 +121,7 @@ pub async fn store(
         _ => "MEMBER",
     };
 
-    let role_id = match sqlx::query_scalar!(
+    let role_id: Uuid = sqlx::query_scalar!(
         r#"
         SELECT id AS "id: Uuid"
         FROM Roles
@@ -132,105 +130,56 @@ pub async fn store(
         user_role
     )
     .fetch_one(&db)
-    .await 
-    {
-        Ok(role_id) => role_id,
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ApiResponse::error(
-                    e.to_string().as_ref(),
-                ))
-            );
-        }    
-    };
+    .await?;
 
-    let result = sqlx::query!(
-        "INSERT INTO Users (id, name, email, password, role_id) VALUES (?, ?, ?, ?, ?)",
-        new_user_id,
-        payload.name,
-        payload.email,
-        password,
-        role_id
+    let token = create_user_tx(&db, new_user_id, &payload, &password, role_id)
+        .await
+        .or_conflict("Email has been registered")?;
+
+    //New accounts verify their email too
+    send_verification_email(mailer, &payload.name, &payload.email, &token);
+
+    //Get newly created user data
+    let user = sqlx::query!(
+        r#"
+            SELECT id AS "id: Uuid", name, email, email_verified_at, role_id AS "role_id: Uuid", created_at, updated_at
+            FROM Users
+            WHERE id = ?
+        "#,
+        new_user_id
     )
-    .execute(&db)
-    .await;
-
-    match result {
-        Ok(result) => {
-            //Get newly created user data
-            let user = sqlx::query!(
-                r#"
-                    SELECT id AS "id: Uuid", name, email, role_id AS "role_id: Uuid", created_at, updated_at
-                    FROM Users
-                    WHERE id = ?
-                "#,
-                new_user_id
-            )
-            .fetch_one(&db)
-            .await;
-
-            match user {
-                Ok(user) => {
-                    let response = UserNewResponse {
-                        id: user.id,
-                        name: user.name,
-                        email: user.email,
-                        role_id: user.role_id.expect("User role not defined"),
-                        created_at: user.created_at,
-                        updated_at: user.updated_at
-                    };
-
-                    (
-                        //Send 201 response Created
-                        StatusCode::CREATED,
-                        Json(ApiResponse::success(
-                            "User created succesfully", 
-                            json!(response)))
-                    )
-                },
-                Err(_) => (
-                    //Send 500 response Internal Server Error
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    Json(ApiResponse::error(
-                        "Failed to fetch user data"
-                    ))
-                )
-            }
-        },
+    .fetch_one(&db)
+    .await?;
 
-        Err(e) => {
-            if e.to_string().contains("Duplicate entry") {
-                (
-                    //Send 409 response Conflict
-                    StatusCode::CONFLICT,
-                    Json(ApiResponse::error(
-                        "Email has been registered"
-                    ))
-                )
-            } else {
-                (
-                    //Send 500 response Internal Server Error
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    Json(ApiResponse::error(
-                        e.to_string().as_ref()
-                    ))
-                )
-            }
-        } 
-    }
+    let response = UserNewResponse {
+        id: user.id,
+        name: user.name,
+        email: user.email,
+        email_verified_at: user.email_verified_at,
+        role_id: user.role_id.expect("User role not defined"),
+        created_at: user.created_at,
+        updated_at: user.updated_at
+    };
+
+    Ok((
+        //Send 201 response Created
+        StatusCode::CREATED,
+        Json(ApiResponse::success(
+            "User created succesfully", 
+            json!(response)))
+    ))
 }
 
 //Handler to get user data by ID
 pub async fn get_user_by_id(
     Path(id): Path<Uuid>,
     Extension(db) : Extension<MySqlPool>,
-) -> (StatusCode, Json<ApiResponse<Value>>) {
+) -> ApiResult {
 
     //Get user data by id
-    let user = match sqlx::query!(
+    let user = sqlx::query!(
         r#"
-        SELECT id AS "id: Uuid", name, email, role_id AS "role_id: Uuid", created_at, updated_at
+        SELECT id AS "id: Uuid", name, email, email_verified_at, role_id AS "role_id: Uuid", created_at, updated_at
         FROM Users
         Where id = ?
         "#,
@@ -238,298 +187,431 @@ pub async fn get_user_by_id(
     ) 
     .fetch_one(&db)
     .await
-    {
-        Ok(user) => user,
-        Err(sqlx::Error::RowNotFound) => {
-            return (
-                //Send 404 responds Not Found
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ApiResponse::error(
-                    "User not found"
-                ))
-            );
-        },
-        Err(e) => {
-            eprintln!("Database error: {}", e);
-            return (
-                //Send 500 responds Internal Server Error
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ApiResponse::error(
-                    "Failed to fetch user"
-                ))
-            );
-        }
-    };
+    .or_not_found("User not found")?;
 
     let response = UserNewResponse {
         id: user.id,
         name: user.name,
         email: user.email,
+        email_verified_at: user.email_verified_at,
         role_id: user.role_id.expect("User role is not set"),
         created_at: user.created_at,
         updated_at: user.updated_at,
     };
 
-    (
+    Ok((
         StatusCode::OK,
         Json(ApiResponse::success(
             "User Details", 
             json!(response)))
+    ))
+}
+
+// Helper to update a user for an admin in one transaction.
+// A new password ends every session of the user, a new email has to be verified again and the token to email is returned
+async fn update_user_tx(
+    db: &MySqlPool,
+    actor_id: Uuid,
+    id: Uuid,
+    payload: &UserUpdateRequest,
+    password: Option<&str>,
+    email_changed: bool,
+) -> Result<Option<String>, sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    sqlx::query!(
+        "UPDATE Users SET name = ?, email = ? WHERE id = ?",
+        payload.name,
+        payload.email,
+        id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    if let Some(password) = password {
+        sqlx::query!(
+            "UPDATE Users SET password = ? WHERE id = ?",
+            password,
+            id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            "UPDATE Refresh_Tokens SET revoked_at = UTC_TIMESTAMP() WHERE user_id = ? AND revoked_at IS NULL",
+            id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        bump_user_token_version(&mut *tx, id).await?;
+    }
+
+    let token = if email_changed {
+        sqlx::query!(
+            "UPDATE Users SET email_verified_at = NULL WHERE id = ?",
+            id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            "DELETE FROM Email_Verifications WHERE user_id = ? AND used_at IS NULL",
+            id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        Some(issue_verification_token(&mut *tx, id).await?)
+    } else {
+        None
+    };
+
+    record_audit(
+        &mut *tx,
+        Some(actor_id),
+        "user.update",
+        "user",
+        Some(id),
+        json!({
+            "name": payload.name,
+            "email": payload.email,
+            "email_changed": email_changed,
+            "password_changed": password.is_some(),
+        }),
     )
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(token)
 }
 
 // Handler to update user data
 #[axum::debug_handler]
 pub async fn update_user(
     Extension(db) : Extension<MySqlPool>,
+    Extension(claims): Extension<Claims>,
+    Extension(mailer): Extension<SharedMailer>,
     Path(id): Path<Uuid>,
-    Json(payload): Json<UserUpdateRequest>
-) -> (StatusCode, Json<ApiResponse<Value>>) {
-    //Validate the request
-    if let Err(e) = payload.validate() {
-        let mut field_errors: HashMap<String, Vec<String>> = HashMap::new();
-
-        for (field, errors) in e.field_errors() {
-            let messages = errors
-                .iter()
-                .filter_map(|e| e.message.as_ref())
-                .map(|m| m.to_string())
-                .collect::<Vec<String>>();
-
-            field_errors.insert(field.to_string(), messages);
-        }
-
-        return (
-            //Send 422 response Unprocessable Entity
-            StatusCode::UNPROCESSABLE_ENTITY,
-            Json(ApiResponse {
-                status: false,
-                message: "Failed to validate".to_string(),
-                data: Some(json!(field_errors))
-            })
-        );
-    };
+    ValidatedJson(payload): ValidatedJson<UserUpdateRequest>
+) -> ApiResult {
+    //Own account goes through /api/me, a new password there needs the current one
+    if id == claims.sub {
+        return Err(AppError::Forbidden(
+            "Use PATCH /api/me and PUT /api/me/password to update your own account".to_string()
+        ));
+    }
 
     if let Some(password) = &payload.password {
         if !password.is_empty() && password.len() < 6 {
-            let mut errors: HashMap<String, Vec<String>> = HashMap::new();
-            errors.insert(
-                "password".to_string(), 
-                vec!["Password must be 6 characters".to_string()]
-            );
-
-            return (
-                //Send 422 response Unprocessable Entity
-                StatusCode::UNPROCESSABLE_ENTITY,
-                Json(ApiResponse {
-                    status: false,
-                    message: "Failed to validate password".to_string(),
-                    data: Some(json!(errors)),
-                })
-            );
+            return Err(AppError::field("password", "Password must be 6 characters"));
         }
     };
 
     //Check if user exist
-    let user_exist = match sqlx::query!(
-        "SELECT id FROM Users Where id = ?",
+    let current_email: String = sqlx::query_scalar!(
+        "SELECT email FROM Users Where id = ?",
         id
     )
     .fetch_one(&db)
     .await
-    {
-        Ok(user) => user,
-        Err(sqlx::Error::RowNotFound) => {
-            return (
-                //Send 404 response Not Found
-                StatusCode::NOT_FOUND,
-                Json(ApiResponse::error(
-                    "User with provided id is not found"
-                ))
-            );
-        },
-        Err(_) => {
-            return (
-                //Send 500 response Internal Server Error
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ApiResponse::error(
-                    "System error"
-                ))
-            );
-        }
-    };
+    .or_not_found("User with provided id is not found")?;
+
+    let email_changed = !payload.email.eq_ignore_ascii_case(&current_email);
 
     //Check email uniqueness
     let email_exists = sqlx::query!(
         "SELECT id FROM Users WHERE email = ? AND id != ?",
         payload.email,
-        user_exist.id
+        id
     )
     .fetch_optional(&db)
-    .await;
-
-    if let Ok(Some(_)) = email_exists {
-        return (
-            //Send conflict response
-            StatusCode::CONFLICT,
-            Json(ApiResponse::error(
-                "Email has been registered",
-            ))
-        );
+    .await?;
+
+    if email_exists.is_some() {
+        return Err(AppError::Conflict("Email has been registered".to_string()));
     }
 
-    //Update user
-    let result = match &payload.password {
-        Some(password) if !password.is_empty() => {
-            //Hash password using Bcrypt
-            let hashed = match hash(password, 10) {
-                Ok(h) => h,
-                Err(_) => {
-                    return (
-                        //Send 500 response Internal Server Error
-                        StatusCode::INTERNAL_SERVER_ERROR,
-                        Json(ApiResponse::error(
-                            "Failed to encrypt password",
-                        ))
-                    );
-                }
-            };
-            
-            sqlx::query!(
-                "UPDATE Users SET name = ?, email = ?, password = ? WHERE id = ?",
-                payload.name,
-                payload.email,
-                hashed,
-                id
-            )
-            .execute(&db)
-            .await
-        },
-        _ => {
-            //Update user without password
-            sqlx::query!(
-                "UPDATE Users SET name = ?, email = ? WHERE id = ?",
-                payload.name,
-                payload.email,
-                id
-            )
-            .execute(&db)
-            .await
-        }
+    //Hash the new password using Bcrypt, an empty password keeps the old one
+    let hashed = match &payload.password {
+        Some(password) if !password.is_empty() => Some(
+            hash(password, 10)
+                .map_err(|_| AppError::Internal("Failed to encrypt password".to_string()))?
+        ),
+        _ => None,
     };
 
-    if let Err(_) = result {
-        return (
-            //Send 500 response Internal Server Error
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ApiResponse::error(
-                "Failed to update user",
-            ))
-        );
+    //Update user, a concurrent signup with the same email still ends as a conflict
+    let token = update_user_tx(&db, claims.sub, id, &payload, hashed.as_deref(), email_changed)
+        .await
+        .or_conflict("Email has been registered")?;
+
+    //A new email is verified by its owner before it can be used to login
+    if let Some(token) = token {
+        send_verification_email(mailer, &payload.name, &payload.email, &token);
     }
 
     //Get new user data
     let user = sqlx::query!(
         r#"
-        SELECT id AS "id: Uuid", name, email, role_id AS "role_id: Uuid", created_at, updated_at
+        SELECT id AS "id: Uuid", name, email, email_verified_at, role_id AS "role_id: Uuid", created_at, updated_at
         FROM Users
         WHERE id =?
         "#,
         id
     )
     .fetch_one(&db)
-    .await;
-
-    match user {
-        Ok(user) => {
-            let response = UserNewResponse {
-                id: user.id,
-                name: user.name,
-                email: user.email,
-                role_id: user.role_id.expect("User role is not set"),
-                created_at: user.created_at,
-                updated_at: user.updated_at,
-            };
-
-            return (
-                //Send 200 response Ok
-                StatusCode::OK,
-                Json(ApiResponse::success(
-                    "User updated successfully", 
-                    json!(response))),
-            );
-        },
-        Err(_) => {
-            return (
-                //Send 500 Internal Server Error
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ApiResponse::error(
-                    "Failed to get recent updated user data",
-                ))
-            );
-        }
+    .await?;
+
+    let response = UserNewResponse {
+        id: user.id,
+        name: user.name,
+        email: user.email,
+        email_verified_at: user.email_verified_at,
+        role_id: user.role_id.expect("User role is not set"),
+        created_at: user.created_at,
+        updated_at: user.updated_at,
+    };
+
+    Ok((
+        //Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "User updated successfully", 
+            json!(response))),
+    ))
+}
+
+// Result of an admin deleting a user, OwnsKosts and HasInvoices leave the user untouched
+enum DeleteUserOutcome {
+    Deleted,
+    OwnsKosts,
+    HasInvoices,
+}
+
+// Helper to delete a user for an admin, the audit row is written first and keeps the target id.
+// Users that still own kosts are kept, deleting them would silently delete the kosts with their rooms and bookings
+async fn delete_user_tx(
+    db: &MySqlPool,
+    actor_id: Uuid,
+    id: Uuid,
+) -> Result<DeleteUserOutcome, sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    let email: String = sqlx::query_scalar!(
+        "SELECT email FROM Users WHERE id = ? FOR UPDATE",
+        id
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
+    let kost_count: i64 = sqlx::query_scalar!(
+        r#"SELECT COUNT(*) AS "count!: i64" FROM Kosts WHERE user_id = ?"#,
+        id
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
+    if kost_count > 0 {
+        return Ok(DeleteUserOutcome::OwnsKosts);
+    }
+
+    // Invoices and payments are kept, so a tenant with billed bookings cannot be removed
+    let invoice_count: i64 = sqlx::query_scalar!(
+        r#"
+        SELECT COUNT(*) AS "count!: i64"
+        FROM Invoices i
+        JOIN Bookings b ON b.id = i.booking_id
+        WHERE b.user_id = ?
+        "#,
+        id
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
+    if invoice_count > 0 {
+        return Ok(DeleteUserOutcome::HasInvoices);
     }
+
+    record_audit(
+        &mut *tx,
+        Some(actor_id),
+        "user.delete",
+        "user",
+        Some(id),
+        json!({ "email": email }),
+    )
+    .await?;
+
+    sqlx::query!(
+        "DELETE FROM Users WHERE id = ?",
+        id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(DeleteUserOutcome::Deleted)
 }
 
 // Handler to delete user data
 pub async fn delete_user(
     Path(id): Path<Uuid>,
     Extension(db) : Extension<MySqlPool>,
-) -> (StatusCode, Json<ApiResponse<Value>>) {
+    Extension(claims): Extension<Claims>,
+) -> ApiResult {
+    //Own account goes through DELETE /api/me, which asks for the password
+    if id == claims.sub {
+        return Err(AppError::Forbidden("Use DELETE /api/me to delete your own account".to_string()));
+    }
+
     //Check user
-    let user = match sqlx::query!(
+    sqlx::query!(
         "SELECT id FROM Users WHERE id = ?",
         id
     )
     .fetch_one(&db)
-    .await {
-        Ok(user) => user,
-        Err(sqlx::Error::RowNotFound) => {
-            return (
-                //Send 404 response Not Found
-                StatusCode::NOT_FOUND,
-                Json(ApiResponse::error(
-                    "User with provided id is not found",
-                ))
-            );
+    .await
+    .or_not_found("User with provided id is not found")?;
+
+    //Delete user from database
+    match delete_user_tx(&db, claims.sub, id).await.or_not_found("User with provided id is not found")? {
+        DeleteUserOutcome::Deleted => {},
+        DeleteUserOutcome::OwnsKosts => {
+            return Err(AppError::Conflict(
+                "User still owns kosts, delete or hand over the kosts first".to_string()
+            ));
         },
-        Err(e) => {
-            eprintln!("Database error : {}", e);
-            return (
-                //Send 500 response Internal Server Error
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ApiResponse::error(
-                    "System Error"
-                ))
-            );
-        }
+        DeleteUserOutcome::HasInvoices => {
+            return Err(AppError::Conflict(
+                "User has bookings with invoices, the billing history cannot be deleted".to_string()
+            ));
+        },
+    }
+
+    Ok((
+        //Send 200 response ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "User has been deleted", 
+            json!(null)))
+    ))
+}
+
+// Result of a role change, everything except Changed leaves the user untouched
+enum RoleChangeOutcome {
+    Changed,
+    UserNotFound,
+    RoleNotFound,
+}
+
+// Helper to give a user another role in one transaction, the old tokens stop working
+async fn change_user_role_tx(
+    db: &MySqlPool,
+    actor_id: Uuid,
+    id: Uuid,
+    role_id: Uuid,
+) -> Result<RoleChangeOutcome, sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    let from_role_id: Option<Uuid> = match sqlx::query_scalar!(
+        r#"SELECT role_id AS "role_id: Uuid" FROM Users WHERE id = ? FOR UPDATE"#,
+        id
+    )
+    .fetch_optional(&mut *tx)
+    .await?
+    {
+        Some(role_id) => role_id,
+        None => return Ok(RoleChangeOutcome::UserNotFound),
     };
 
-    //Delete user from database
-    let result = sqlx::query!(
-        "DELETE FROM Users WHERE id = ?",
+    let role: String = match sqlx::query_scalar!(
+        "SELECT name FROM Roles WHERE id = ?",
+        role_id
+    )
+    .fetch_optional(&mut *tx)
+    .await?
+    {
+        Some(role) => role,
+        None => return Ok(RoleChangeOutcome::RoleNotFound),
+    };
+
+    sqlx::query!(
+        "UPDATE Users SET role_id = ? WHERE id = ?",
+        role_id,
         id
     )
-    .execute(&db)
-    .await;
-
-    match result {
-        Ok(_) => (
-            //Send 200 response ok
-            StatusCode::OK,
-            Json(ApiResponse::success(
-                "User has been deleted", 
-                json!(null)))
-        ),
-        Err(e) => {
-            eprintln!("Database error: {}", e);
-            (
-                //Send 500 response Internal Server Error
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ApiResponse::error(
-                    "Failed to delete user",
-                ))
-            )
-        } 
+    .execute(&mut *tx)
+    .await?;
+
+    bump_user_token_version(&mut *tx, id).await?;
+
+    record_audit(
+        &mut *tx,
+        Some(actor_id),
+        "user.role_change",
+        "user",
+        Some(id),
+        json!({ "from_role_id": from_role_id, "to_role_id": role_id, "to_role": role }),
+    )
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(RoleChangeOutcome::Changed)
+}
+
+// Handler to change the role of a user
+pub async fn change_user_role(
+    Extension(db) : Extension<MySqlPool>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+    ValidatedJson(payload): ValidatedJson<UserRoleRequest>,
+) -> ApiResult {
+    //An admin removing their own role could lock everybody out
+    if id == claims.sub {
+        return Err(AppError::Forbidden("You cannot change your own role, ask another admin".to_string()));
+    }
+
+    match change_user_role_tx(&db, claims.sub, id, payload.role_id).await? {
+        RoleChangeOutcome::Changed => {},
+        RoleChangeOutcome::UserNotFound => {
+            return Err(AppError::NotFound("User with provided id is not found".to_string()));
+        },
+        RoleChangeOutcome::RoleNotFound => {
+            return Err(AppError::NotFound("Role with provided id is not found".to_string()));
+        },
     }
+
+    //Get new user data
+    let user = sqlx::query!(
+        r#"
+        SELECT id AS "id: Uuid", name, email, email_verified_at, role_id AS "role_id: Uuid", created_at, updated_at
+        FROM Users
+        WHERE id = ?
+        "#,
+        id
+    )
+    .fetch_one(&db)
+    .await?;
+
+    let response = UserNewResponse {
+        id: user.id,
+        name: user.name,
+        email: user.email,
+        email_verified_at: user.email_verified_at,
+        role_id: user.role_id.expect("User role is not set"),
+        created_at: user.created_at,
+        updated_at: user.updated_at,
+    };
+
+    Ok((
+        //Send 200 response Ok
+        StatusCode::OK,
+        Json(ApiResponse::success(
+            "User role changed successfully",
+            json!(response)))
+    ))
 }
\ No newline at end of file
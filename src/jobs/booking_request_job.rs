@@ -0,0 +1,34 @@
+use std::time::Duration;
+
+use sqlx::MySqlPool;
+
+// Background task, periodically expire booking requests the owner did not answer in time
+pub async fn run(db: MySqlPool, every: Duration) {
+    let mut interval = tokio::time::interval(every);
+
+    loop {
+        interval.tick().await;
+
+        match expire_requests(&db).await {
+            Ok(0) => {},
+            Ok(expired) => println!("Booking request job: {} requests expired", expired),
+            Err(e) => eprintln!("Booking request job error: {}", e),
+        }
+    }
+}
+
+// Mark open requests past their expiry as EXPIRED, returns the number of requests expired
+async fn expire_requests(db: &MySqlPool) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query!(
+        r#"
+        UPDATE Booking_Requests
+        SET status = 'EXPIRED'
+        WHERE status = 'REQUESTED'
+            AND expires_at <= UTC_TIMESTAMP()
+        "#
+    )
+    .execute(db)
+    .await?;
+
+    Ok(result.rows_affected())
+}
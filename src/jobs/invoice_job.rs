@@ -0,0 +1,25 @@
+use std::time::Duration;
+
+use sqlx::MySqlPool;
+
+// Import invoice helpers
+use crate::utils::invoice::{
+    current_billing_period,
+    generate_invoices,
+};
+
+// Background task, periodically generate the invoices of the running billing month for every active booking.
+// Bookings that already have an invoice for the month are skipped, so running it often is harmless
+pub async fn run(db: MySqlPool, every: Duration) {
+    let mut interval = tokio::time::interval(every);
+
+    loop {
+        interval.tick().await;
+
+        match generate_invoices(&db, None, current_billing_period()).await {
+            Ok(0) => {},
+            Ok(generated) => println!("Invoice job: {} invoices generated", generated),
+            Err(e) => eprintln!("Invoice job error: {}", e),
+        }
+    }
+}
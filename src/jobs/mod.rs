@@ -0,0 +1,4 @@
+pub mod invoice_job;
+pub mod overdue_job;
+pub mod booking_request_job;
+pub mod token_cleanup_job;
@@ -0,0 +1,59 @@
+use std::time::Duration;
+
+use sqlx::MySqlPool;
+
+// Background task, periodically flip unpaid invoices past their due date plus the kost grace period to OVERDUE
+pub async fn run(db: MySqlPool, every: Duration) {
+    let mut interval = tokio::time::interval(every);
+
+    loop {
+        interval.tick().await;
+
+        match mark_overdue(&db).await {
+            Ok(0) => {},
+            Ok(marked) => println!("Overdue job: {} invoices marked as overdue", marked),
+            Err(e) => eprintln!("Overdue job error: {}", e),
+        }
+    }
+}
+
+// Mark the invoices and their bookings as OVERDUE, returns the number of invoices marked.
+// Invoices of cancelled bookings are left alone, nobody pays them anymore
+async fn mark_overdue(db: &MySqlPool) -> Result<u64, sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    let invoices = sqlx::query!(
+        r#"
+        UPDATE Invoices i
+        JOIN Bookings b ON b.id = i.booking_id
+        JOIN Rooms r ON r.id = b.room_id
+        JOIN Kosts k ON k.id = r.kost_id
+        SET i.status = 'OVERDUE'
+        WHERE i.status = 'PENDING'
+            AND b.payment_status <> 'CANCELLED'
+            AND DATE_ADD(i.due_date, INTERVAL k.grace_period_days DAY) < UTC_DATE()
+        "#
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    // A paid booking can get a new invoice that goes overdue too
+    sqlx::query!(
+        r#"
+        UPDATE Bookings b
+        SET b.payment_status = 'OVERDUE'
+        WHERE b.payment_status IN ('PENDING', 'PAID')
+            AND EXISTS (
+                SELECT 1
+                FROM Invoices i
+                WHERE i.booking_id = b.id AND i.status = 'OVERDUE'
+            )
+        "#
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(invoices.rows_affected())
+}
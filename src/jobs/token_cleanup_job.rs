@@ -0,0 +1,65 @@
+use std::time::Duration;
+
+use sqlx::MySqlPool;
+
+// Background task, periodically remove refresh tokens, revoked access tokens, password reset and email verification tokens
+// that expired, so the revocation check in auth stays small. Login throttles that no longer count are removed too
+pub async fn run(db: MySqlPool, every: Duration) {
+    let mut interval = tokio::time::interval(every);
+
+    loop {
+        interval.tick().await;
+
+        match purge_expired_tokens(&db).await {
+            Ok(0) => {},
+            Ok(purged) => println!("Token cleanup job: {} expired tokens removed", purged),
+            Err(e) => eprintln!("Token cleanup job error: {}", e),
+        }
+    }
+}
+
+// Delete the expired tokens, returns the number of rows removed
+async fn purge_expired_tokens(db: &MySqlPool) -> Result<u64, sqlx::Error> {
+    let refresh_tokens = sqlx::query!(
+        "DELETE FROM Refresh_Tokens WHERE expires_at <= UTC_TIMESTAMP()"
+    )
+    .execute(db)
+    .await?;
+
+    let revoked_tokens = sqlx::query!(
+        "DELETE FROM Revoked_Tokens WHERE expires_at <= UTC_TIMESTAMP()"
+    )
+    .execute(db)
+    .await?;
+
+    let password_resets = sqlx::query!(
+        "DELETE FROM Password_Resets WHERE expires_at <= UTC_TIMESTAMP()"
+    )
+    .execute(db)
+    .await?;
+
+    let email_verifications = sqlx::query!(
+        "DELETE FROM Email_Verifications WHERE expires_at <= UTC_TIMESTAMP()"
+    )
+    .execute(db)
+    .await?;
+
+    // Lockouts are counted from the start again after a day, see login_throttle
+    let login_throttles = sqlx::query!(
+        r#"
+        DELETE FROM Login_Throttles
+        WHERE last_failed_at <= UTC_TIMESTAMP() - INTERVAL 1 DAY
+            AND (locked_until IS NULL OR locked_until <= UTC_TIMESTAMP())
+        "#
+    )
+    .execute(db)
+    .await?;
+
+    Ok(
+        refresh_tokens.rows_affected()
+            + revoked_tokens.rows_affected()
+            + password_resets.rows_affected()
+            + email_verifications.rows_affected()
+            + login_throttles.rows_affected()
+    )
+}
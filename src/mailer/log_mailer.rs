@@ -0,0 +1,17 @@
+use crate::mailer::{Email, MailFuture, Mailer};
+
+// Mailer that prints the emails to the console instead of sending them, for local development
+pub struct LogMailer;
+
+impl Mailer for LogMailer {
+    fn send<'a>(&'a self, email: &'a Email) -> MailFuture<'a> {
+        Box::pin(async move {
+            println!(
+                "Mail to: {}\nSubject: {}\n\n{}\n",
+                email.to, email.subject, email.body
+            );
+
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,51 @@
+use std::{error::Error, future::Future, pin::Pin, sync::Arc};
+
+use crate::config::app_config::{AppConfig, MailDriver};
+
+pub mod log_mailer;
+pub mod smtp_mailer;
+
+// Error of a failed send, the handlers only log it
+pub type MailError = Box<dyn Error + Send + Sync>;
+
+// Boxed future, so the mailer can be used behind a trait object
+pub type MailFuture<'a> = Pin<Box<dyn Future<Output = Result<(), MailError>> + Send + 'a>>;
+
+// Mailer shared with the handlers through Extension
+pub type SharedMailer = Arc<dyn Mailer>;
+
+// Plain text email
+#[derive(Debug, Clone)]
+pub struct Email {
+    pub to: String,
+    pub subject: String,
+    pub body: String,
+}
+
+// Backend for outgoing emails, SMTP in production and the console for local development
+pub trait Mailer: Send + Sync {
+    fn send<'a>(&'a self, email: &'a Email) -> MailFuture<'a>;
+}
+
+// Build the mailer from MAIL_DRIVER
+pub fn from_config(config: &AppConfig) -> SharedMailer {
+    match &config.mail_driver {
+        MailDriver::Log => Arc::new(log_mailer::LogMailer),
+        MailDriver::Smtp(smtp) => match smtp_mailer::SmtpMailer::new(smtp, config.mail_from.clone()) {
+            Ok(mailer) => Arc::new(mailer),
+            Err(e) => {
+                eprintln!("Failed to set up SMTP mailer: {}", e);
+                std::process::exit(1);
+            }
+        },
+    }
+}
+
+// Send the email in the background, so the response does not wait for the mail server
+pub fn send_in_background(mailer: SharedMailer, email: Email) {
+    tokio::spawn(async move {
+        if let Err(e) = mailer.send(&email).await {
+            eprintln!("Mail error: failed to send \"{}\" to {}: {}", email.subject, email.to, e);
+        }
+    });
+}
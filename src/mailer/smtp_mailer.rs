@@ -0,0 +1,55 @@
+use lettre::{
+    message::{header::ContentType, Mailbox},
+    transport::smtp::{authentication::Credentials, Error},
+    AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
+};
+
+use crate::config::app_config::SmtpConfig;
+use crate::mailer::{Email, MailFuture, Mailer};
+
+// Port for implicit TLS, every other port upgrades the connection with STARTTLS
+const SMTPS_PORT: u16 = 465;
+
+// Mailer that sends the emails through an SMTP server
+pub struct SmtpMailer {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+}
+
+impl SmtpMailer {
+    pub fn new(config: &SmtpConfig, from: Mailbox) -> Result<Self, Error> {
+        let builder = if config.port == SMTPS_PORT {
+            AsyncSmtpTransport::<Tokio1Executor>::relay(&config.host)?
+        } else {
+            AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&config.host)?
+        };
+
+        let mut builder = builder.port(config.port);
+
+        if let (Some(username), Some(password)) = (&config.username, &config.password) {
+            builder = builder.credentials(Credentials::new(username.clone(), password.clone()));
+        }
+
+        Ok(Self {
+            transport: builder.build(),
+            from,
+        })
+    }
+}
+
+impl Mailer for SmtpMailer {
+    fn send<'a>(&'a self, email: &'a Email) -> MailFuture<'a> {
+        Box::pin(async move {
+            let message = Message::builder()
+                .from(self.from.clone())
+                .to(email.to.parse()?)
+                .subject(&email.subject)
+                .header(ContentType::TEXT_PLAIN)
+                .body(email.body.clone())?;
+
+            self.transport.send(message).await?;
+
+            Ok(())
+        })
+    }
+}
@@ -1,7 +1,9 @@
 use axum::{Router, Extension};
 use dotenvy::dotenv;
-use std::net::SocketAddr;
-use tower_http::cors::{CorsLayer, Any};
+use std::{net::SocketAddr, time::Duration};
+use tower_http::cors::{AllowOrigin, CorsLayer, Any};
+
+use config::app_config::CorsOrigins;
 
 mod config;
 mod models;
@@ -10,18 +12,47 @@ mod middlewares;
 mod schemas;
 mod handlers;
 mod routes;
+mod jobs;
+mod storage;
+mod mailer;
 
 #[tokio::main]
 async fn main() {
     // Load the environment file
     dotenv().ok();
 
+    // Load and check the config, the server refuses to start with an invalid one
+    let config = config::app_config::init();
+
     // Try to connect to database
-    let db = config::database::connect().await;
+    let db = config::database::connect(config).await;
+
+    // Generate the invoices of the running month for active bookings
+    tokio::spawn(jobs::invoice_job::run(db.clone(), config.invoice_job_interval));
+
+    // Run the overdue invoice job alongside the server
+    tokio::spawn(jobs::overdue_job::run(db.clone(), config.overdue_job_interval));
+
+    // Expire unanswered booking requests
+    tokio::spawn(jobs::booking_request_job::run(db.clone(), config.booking_request_job_interval));
+
+    // Remove expired refresh, revoked, password reset and email verification tokens once a day
+    tokio::spawn(jobs::token_cleanup_job::run(db.clone(), Duration::from_secs(24 * 60 * 60)));
+
+    // Storage for uploaded files
+    let storage = storage::from_config(config);
+
+    // Mailer for outgoing emails
+    let mailer = mailer::from_config(config);
 
     // Cors configuration
+    let allow_origin = match &config.cors_origins {
+        CorsOrigins::Any => AllowOrigin::any(),
+        CorsOrigins::List(origins) => AllowOrigin::list(origins.clone()),
+    };
+
     let cors = CorsLayer::new()
-        .allow_origin(Any)
+        .allow_origin(allow_origin)
         .allow_methods(Any)
         .allow_headers(Any);
 
@@ -31,17 +62,23 @@ async fn main() {
         .merge(routes::user_route::user_routes())
         .merge(routes::kost_route::kost_route())
         .merge(routes::room_route::room_route())
+        .merge(routes::booking_route::booking_route())
+        .merge(routes::invoice_route::invoice_route())
+        .merge(routes::dashboard_route::dashboard_route())
+        .merge(routes::kost_image_route::kost_image_route())
+        .merge(routes::me_route::me_route())
+        .merge(routes::public_route::public_route())
+        .merge(routes::booking_request_route::booking_request_route())
+        .merge(routes::role_route::role_route())
+        .merge(routes::owner_application_route::owner_application_route())
+        .merge(routes::upload_route::upload_route())
         .layer(Extension(db))
+        .layer(Extension(storage))
+        .layer(Extension(mailer))
         .layer(cors);
 
-    //Take port from environment variable,
-    let port = std::env::var("APP_PORT")
-        .ok()
-        .and_then(|p| p.parse::<u16>().ok())
-        .unwrap_or(3001);
-
     //Server address
-    let addrs = SocketAddr::from(([127,0,0,1], port));
+    let addrs = config.bind_addr;
 
     //Print server to console
     println!("Server is running on http:://{}", addrs);
@@ -49,7 +86,8 @@ async fn main() {
     //Run the server
     axum::serve(
         tokio::net::TcpListener::bind(addrs).await.unwrap(),
-        app
+        // Keep the peer address, login throttling needs the client IP
+        app.into_make_service_with_connect_info::<SocketAddr>()
         )
         .await
         .unwrap();
@@ -1,4 +1,5 @@
 use axum::{
+    Extension,
     extract::Request,
     middleware::Next,
     response::Response,
@@ -6,6 +7,8 @@ use axum::{
     Json,  
 };
 
+use sqlx::MySqlPool;
+
 use crate::utils::jwt::verify_token;
 use crate::utils::response::ApiResponse;
 
@@ -14,6 +17,7 @@ type AuthError = (StatusCode, Json<ApiResponse<()>>);
 
 //Middleware Authentication
 pub async fn auth(
+    Extension(db): Extension<MySqlPool>,
     headers: HeaderMap,
     mut req: Request,
     next: Next,
@@ -40,6 +44,50 @@ pub async fn auth(
             )
         })?;
 
+    //Reject tokens revoked by logout, of deleted users, or issued before the role or permissions changed
+    let user = sqlx::query!(
+        r#"
+        SELECT
+            token_version AS "token_version: u32",
+            EXISTS(SELECT 1 FROM Revoked_Tokens WHERE jti = ?) AS "revoked!: bool"
+        FROM Users
+        WHERE id = ?
+        "#,
+        claims.jti,
+        claims.sub
+    )
+    .fetch_optional(&db)
+    .await
+    .map_err(|e| {
+        eprintln!("Database error: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::<()>::error("System Error"))
+        )
+    })?;
+
+    match user {
+        None => {
+            return Err((
+                StatusCode::UNAUTHORIZED,
+                Json(ApiResponse::<()>::error("Token is not valid"))
+            ));
+        },
+        Some(user) if user.revoked => {
+            return Err((
+                StatusCode::UNAUTHORIZED,
+                Json(ApiResponse::<()>::error("Token has been revoked"))
+            ));
+        },
+        Some(user) if user.token_version != claims.ver => {
+            return Err((
+                StatusCode::UNAUTHORIZED,
+                Json(ApiResponse::<()>::error("Token is outdated, please refresh or login again"))
+            ));
+        },
+        Some(_) => {},
+    }
+
     //Save claim in extension for handler to use
     req.extensions_mut().insert(claims);
 
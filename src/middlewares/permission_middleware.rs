@@ -1,8 +1,10 @@
+use std::{future::Future, pin::Pin};
+
 use axum::{
-    Json, 
-    extract::Request, 
-    http::StatusCode, 
-    middleware::Next, 
+    Json,
+    extract::{Request, State},
+    http::StatusCode,
+    middleware::{from_fn_with_state, FromFnLayer, Next},
     response::Response
 };
 
@@ -12,107 +14,64 @@ use crate::utils::{jwt::Claims, response::ApiResponse};
 // Type alias for permission error
 type PermissionsError = (StatusCode, Json<ApiResponse<()>>);
 
-// Middleware permissions
-pub async fn require_permission_admin(
-    mut req: Request,
-    next: Next,
-) -> Result<Response, PermissionsError> {
-    let admin_permission = vec![
-        "kost:create",
-        "kost:update",
-        "kost:delete",
-        "kost:view_all",
-        "kost:view",
-        "user:create",
-        "user:update",
-        "user:delete",
-        "user:view",
-    ];
+// Permissions a route needs, declared next to the route
+pub type RequiredPermissions = &'static [&'static str];
 
-    let claims = req
-        .extensions()
-        .get::<Claims>();
+// Boxed middleware, so the layer type can be named
+type PermissionsFuture = Pin<Box<dyn Future<Output = Result<Response, PermissionsError>> + Send>>;
+type PermissionsFn = fn(State<RequiredPermissions>, Request, Next) -> PermissionsFuture;
 
-    match claims {
-        Some(claims) => {
-            if !has_permission(claims, &admin_permission) {
-                return Err(
-                    (
-                        // Send 402 response Forbidden
-                        StatusCode::FORBIDDEN,
-                        Json(ApiResponse::<()>::error(
-                            "Only Admin can access"
-                        ))
-                    )
-                );
-            }
-
-            Ok(next.run(req).await)
-        }
-        None => {
-            return Err(
-                (
-                    // Send 401 response Unauthorized
-                    StatusCode::UNAUTHORIZED,
-                    Json(ApiResponse::<()>::error(
-                        "Please login first"
-                    ))
-                )
-            )
-        }
-    }
+// Layer for a route, e.g. `get(get_room_by_id).layer(require_permissions(&["room:view"]))`,
+// the user must have every listed permission
+pub fn require_permissions(
+    permissions: RequiredPermissions,
+) -> FromFnLayer<PermissionsFn, RequiredPermissions, (State<RequiredPermissions>, Request)> {
+    from_fn_with_state(permissions, check_permissions as PermissionsFn)
 }
 
-pub async fn require_permission_owner(
-    mut req: Request,
+// Middleware permissions, compares the route permissions with the permissions in the token
+fn check_permissions(
+    State(permissions): State<RequiredPermissions>,
+    req: Request,
     next: Next,
-) -> Result<Response, PermissionsError> {
-    let owner_permissions = vec![
-        "kost:create",
-        "kost:update",
-        "kost:delete",
-        "kost:view",
-        "room:create",
-        "room:update",
-        "room:delete",
-        "room:view",
-    ];
+) -> PermissionsFuture {
+    Box::pin(async move {
+        let claims = req
+            .extensions()
+            .get::<Claims>();
 
-    let claims = req
-        .extensions()
-        .get::<Claims>();
+        match claims {
+            Some(claims) => {
+                if !has_permission(claims, permissions) {
+                    return Err(
+                        (
+                            // Send 403 response Forbidden
+                            StatusCode::FORBIDDEN,
+                            Json(ApiResponse::error(
+                                "You do not have permission to access this resource"
+                            ))
+                        )
+                    );
+                }
 
-    match claims {
-        Some(claims) => {
-            if !has_permission(claims, &owner_permissions) {
-                return Err(
-                    (
-                        // Send 403 response forbidden
-                        StatusCode::FORBIDDEN,
+                Ok(next.run(req).await)
+            }
+            None => {
+                Err(
+                    (   // Send 401 response Unauthorized
+                        StatusCode::UNAUTHORIZED,
                         Json(ApiResponse::error(
-                            "Only Owner of this kost can access"
+                        "Please login first"
                         ))
                     )
-                );
-            }
-
-            Ok(next.run(req).await)
-        }
-        None => {
-            return Err(
-                (   // Send 401 response Unauthorized
-                    StatusCode::UNAUTHORIZED,
-                    Json(ApiResponse::error(
-                    "Please login first"
-                    ))
                 )
-            );
+            }
         }
-    }
+    })
 }
 
 pub fn has_permission(claims: &Claims, permits: &[&str]) -> bool {
     permits
         .iter()
         .all(|p| claims.permissions.iter().any(|cp| cp == p))
-}
\ No newline at end of file
+}
@@ -0,0 +1,16 @@
+use serde::Serialize;
+use serde_json::Value;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[derive(Serialize)]
+pub struct AuditLog {
+    pub id: Uuid,
+    pub actor_id: Option<Uuid>,
+    pub actor_name: Option<String>,
+    pub action: String,
+    pub target_type: String,
+    pub target_id: Option<Uuid>,
+    pub details: Option<Value>,
+    pub created_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, NaiveDateTime, Utc};
+use uuid::Uuid;
+
+use crate::schemas::booking_schema::PaymentStatus;
+
+#[derive(Serialize)]
+pub struct Booking {
+    pub id: Uuid,
+    pub room_id: Uuid,
+    pub user_id: Uuid,
+    pub check_in: NaiveDateTime,
+    pub check_out: Option<NaiveDateTime>,
+    pub monthly_rent: u64,
+    pub payment_status: PaymentStatus,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct BookingPath {
+    pub kost_id: Uuid,
+    pub room_id: Uuid,
+    pub booking_id: Uuid,
+}
+
+#[derive(Serialize)]
+pub struct TenantBooking {
+    pub id: Uuid,
+    pub kost_id: Uuid,
+    pub kost_name: String,
+    pub room_id: Uuid,
+    pub room_number: u32,
+    pub check_in: NaiveDateTime,
+    pub check_out: Option<NaiveDateTime>,
+    pub monthly_rent: u64,
+    pub currency: String,
+    pub payment_status: PaymentStatus,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>
+}
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, NaiveDateTime, Utc};
+use uuid::Uuid;
+
+use crate::schemas::booking_request_schema::BookingRequestStatus;
+
+#[derive(Serialize)]
+pub struct BookingRequest {
+    pub id: Uuid,
+    pub kost_id: Uuid,
+    pub room_id: Uuid,
+    pub room_number: u32,
+    pub user_id: Uuid,
+    pub check_in: NaiveDateTime,
+    pub check_out: Option<NaiveDateTime>,
+    pub message: Option<String>,
+    pub status: BookingRequestStatus,
+    pub rejection_reason: Option<String>,
+    pub booking_id: Option<Uuid>,
+    pub decided_by: Option<Uuid>,
+    pub decided_at: Option<DateTime<Utc>>,
+    pub expires_at: NaiveDateTime,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct BookingRequestPath {
+    pub kost_id: Uuid,
+    pub request_id: Uuid,
+}
@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, NaiveDate, Utc};
+use uuid::Uuid;
+
+use crate::schemas::booking_schema::PaymentStatus;
+
+#[derive(Serialize)]
+pub struct Invoice {
+    pub id: Uuid,
+    pub booking_id: Uuid,
+    pub room_id: Uuid,
+    pub room_number: u32,
+    pub user_id: Uuid,
+    pub period_start: NaiveDate,
+    pub amount: u64,
+    pub paid_amount: u64,
+    pub outstanding: u64,
+    pub due_date: NaiveDate,
+    pub status: PaymentStatus,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct InvoicePath {
+    pub kost_id: Uuid,
+    pub invoice_id: Uuid,
+}
@@ -10,6 +10,17 @@ pub struct Kost {
     pub kost_address: String,
     pub kost_contact: String,
     pub kost_desc: String,
+    pub grace_period_days: u32,
     pub created_at: Option<DateTime<Utc>>,
     pub updated_at: Option<DateTime<Utc>>,
-}
\ No newline at end of file
+}
+
+// Kost fields that can be shown to anyone, without the owner account
+#[derive(Serialize)]
+pub struct PublicKost {
+    pub id: Uuid,
+    pub kost_name: String,
+    pub kost_address: String,
+    pub kost_contact: String,
+    pub kost_desc: String,
+}
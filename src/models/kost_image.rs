@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[derive(Serialize)]
+pub struct KostImage {
+    pub id: Uuid,
+    pub kost_id: Uuid,
+    pub object_key: String,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct KostImagePath {
+    pub kost_id: Uuid,
+    pub image_id: Uuid,
+}
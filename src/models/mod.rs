@@ -1,3 +1,11 @@
 pub mod user;
 pub mod kost;
-pub mod room;
\ No newline at end of file
+pub mod room;
+pub mod booking;
+pub mod invoice;
+pub mod payment;
+pub mod kost_image;
+pub mod booking_request;
+pub mod role;
+pub mod audit_log;
+pub mod owner_application;
\ No newline at end of file
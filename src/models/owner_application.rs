@@ -0,0 +1,23 @@
+use serde::Serialize;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::schemas::owner_application_schema::OwnerApplicationStatus;
+
+#[derive(Serialize)]
+pub struct OwnerApplication {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub user_name: String,
+    pub user_email: String,
+    pub business_name: String,
+    pub business_address: String,
+    pub phone: String,
+    pub description: Option<String>,
+    pub status: OwnerApplicationStatus,
+    pub rejection_reason: Option<String>,
+    pub decided_by: Option<Uuid>,
+    pub decided_at: Option<DateTime<Utc>>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>
+}
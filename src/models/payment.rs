@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, NaiveDateTime, Utc};
+use uuid::Uuid;
+
+use crate::schemas::payment_schema::PaymentMethod;
+
+#[derive(Serialize)]
+pub struct Payment {
+    pub id: Uuid,
+    pub invoice_id: Uuid,
+    pub amount: u64,
+    pub method: PaymentMethod,
+    pub reference: Option<String>,
+    pub paid_at: NaiveDateTime,
+    pub recorded_by: Option<Uuid>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct PaymentPath {
+    pub kost_id: Uuid,
+    pub invoice_id: Uuid,
+    pub payment_id: Uuid,
+}
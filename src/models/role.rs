@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Serialize)]
+pub struct Role {
+    pub id: Uuid,
+    pub name: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Permission {
+    pub id: Uuid,
+    pub name: String,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct RolePermissionPath {
+    pub role_id: Uuid,
+    pub permission_id: Uuid,
+}
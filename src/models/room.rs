@@ -10,6 +10,9 @@ pub struct Room {
     pub kost_id: Uuid,
     pub room_number: u32,
     pub room_vacancy: RoomStatus,
+    pub monthly_rent: u64,
+    pub deposit: Option<u64>,
+    pub currency: String,
     pub created_at: Option<DateTime<Utc>>,
     pub updated_at: Option<DateTime<Utc>>
 }
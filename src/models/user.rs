@@ -1,12 +1,13 @@
 use serde::Serialize;
 use uuid::Uuid;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDateTime, Utc};
 
 #[derive(Serialize)]
 pub struct User {
     pub id: Uuid,
     pub name: String,
     pub email: String,
+    pub email_verified_at: Option<NaiveDateTime>,
     pub role_id: Option<Uuid>,
     pub created_at: Option<DateTime<Utc>>,
     pub updated_at: Option<DateTime<Utc>>
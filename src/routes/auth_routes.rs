@@ -1,14 +1,30 @@
-use axum::{Router, routing::post};
+use axum::{Router, middleware::from_fn, routing::post};
 
 //Import register handle
 use crate::handlers::{
     register_user_handler::register,
     login_handler::login,
+    token_handler::{refresh_token, logout},
+    password_handler::{forgot_password, reset_password},
+    email_verification_handler::verify_email,
 };
 
+//Import auth middleware
+use crate::middlewares::auth_middleware::auth;
+
 //Function to manage route
 pub fn auth_routes() -> Router {
     Router::new()
         .route("/api/register", post(register))
         .route("/api/login", post(login) )
-}
\ No newline at end of file
+        // POST /api/token/refresh -> Swap a refresh token for a new access and refresh token
+        .route("/api/token/refresh", post(refresh_token))
+        // POST /api/logout -> Revoke the refresh token and the current access token
+        .route("/api/logout", post(logout).layer(from_fn(auth)))
+        // POST /api/password/forgot -> Email a password reset link
+        .route("/api/password/forgot", post(forgot_password))
+        // POST /api/password/reset -> Set a new password with the token from the link
+        .route("/api/password/reset", post(reset_password))
+        // POST /api/email/verify -> Verify the email with the token from the link
+        .route("/api/email/verify", post(verify_email))
+}
@@ -0,0 +1,41 @@
+use axum::{
+    Router,
+    middleware::from_fn,
+    routing::{get, post},
+};
+
+// Import booking request handler
+use crate::handlers::booking_request_handler::{
+    get_kost_booking_requests,
+    approve_booking_request,
+    reject_booking_request,
+};
+
+// Import auth middleware
+use crate::middlewares::auth_middleware::auth;
+
+// Import permission middleware
+use crate::middlewares::permission_middleware::require_permissions;
+
+pub fn booking_request_route() -> Router {
+    Router::new()
+        // GET /api/kosts/{kost_id}/booking-requests -> Get the booking requests of the kost
+        .route(
+            "/api/kosts/{kost_id}/booking-requests",
+            get(get_kost_booking_requests)
+                .layer(require_permissions(&["booking:view"]))
+        )
+        // POST /api/kosts/{kost_id}/booking-requests/{request_id}/approve -> Approve the request and create the booking
+        .route(
+            "/api/kosts/{kost_id}/booking-requests/{request_id}/approve",
+            post(approve_booking_request)
+                .layer(require_permissions(&["booking_request:review"]))
+        )
+        // POST /api/kosts/{kost_id}/booking-requests/{request_id}/reject -> Reject the request
+        .route(
+            "/api/kosts/{kost_id}/booking-requests/{request_id}/reject",
+            post(reject_booking_request)
+                .layer(require_permissions(&["booking_request:review"]))
+        )
+        .layer(from_fn(auth))
+}
@@ -0,0 +1,69 @@
+use axum::{
+    Router,
+    middleware::from_fn,
+    routing::{delete, get, post, put},
+};
+
+// Import booking handler
+use crate::handlers::booking_handler::{
+    create_booking,
+    get_all_bookings,
+    get_booking_by_id,
+    update_booking,
+    delete_booking,
+    checkout_booking,
+    cancel_booking,
+};
+
+// Import auth middleware
+use crate::middlewares::auth_middleware::auth;
+
+// Import permission middleware
+use crate::middlewares::permission_middleware::require_permissions;
+
+pub fn booking_route() -> Router {
+    Router::new()
+        // POST /api/kosts/{kost_id}/rooms/{room_id}/bookings -> Record a new booking for the room
+        .route(
+            "/api/kosts/{kost_id}/rooms/{room_id}/bookings",
+            post(create_booking)
+                .layer(require_permissions(&["booking:create"]))
+        )
+        // GET /api/kosts/{kost_id}/rooms/{room_id}/bookings -> Get all bookings of the room
+        .route(
+            "/api/kosts/{kost_id}/rooms/{room_id}/bookings",
+            get(get_all_bookings)
+                .layer(require_permissions(&["booking:view"]))
+        )
+        // GET /api/kosts/{kost_id}/rooms/{room_id}/bookings/{booking_id} -> Get booking by id
+        .route(
+            "/api/kosts/{kost_id}/rooms/{room_id}/bookings/{booking_id}",
+            get(get_booking_by_id)
+                .layer(require_permissions(&["booking:view"]))
+        )
+        // PUT /api/kosts/{kost_id}/rooms/{room_id}/bookings/{booking_id} -> Update booking data
+        .route(
+            "/api/kosts/{kost_id}/rooms/{room_id}/bookings/{booking_id}",
+            put(update_booking)
+                .layer(require_permissions(&["booking:update"]))
+        )
+        // DELETE /api/kosts/{kost_id}/rooms/{room_id}/bookings/{booking_id} -> Delete booking
+        .route(
+            "/api/kosts/{kost_id}/rooms/{room_id}/bookings/{booking_id}",
+            delete(delete_booking)
+                .layer(require_permissions(&["booking:delete"]))
+        )
+        // POST /api/kosts/{kost_id}/rooms/{room_id}/bookings/{booking_id}/checkout -> Check out the tenant
+        .route(
+            "/api/kosts/{kost_id}/rooms/{room_id}/bookings/{booking_id}/checkout",
+            post(checkout_booking)
+                .layer(require_permissions(&["booking:update"]))
+        )
+        // POST /api/kosts/{kost_id}/rooms/{room_id}/bookings/{booking_id}/cancel -> Cancel the booking
+        .route(
+            "/api/kosts/{kost_id}/rooms/{room_id}/bookings/{booking_id}/cancel",
+            post(cancel_booking)
+                .layer(require_permissions(&["booking:update"]))
+        )
+        .layer(from_fn(auth))
+}
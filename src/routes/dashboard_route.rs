@@ -0,0 +1,25 @@
+use axum::{
+    Router,
+    middleware::from_fn,
+    routing::get,
+};
+
+// Import dashboard handler
+use crate::handlers::dashboard_handler::get_dashboard;
+
+// Import auth middleware
+use crate::middlewares::auth_middleware::auth;
+
+// Import permission middleware
+use crate::middlewares::permission_middleware::require_permissions;
+
+pub fn dashboard_route() -> Router {
+    Router::new()
+        // GET /api/dashboard -> Summary of every kost owned by the current user
+        .route(
+            "/api/dashboard",
+            get(get_dashboard)
+                .layer(require_permissions(&["dashboard:view"]))
+        )
+        .layer(from_fn(auth))
+}
@@ -0,0 +1,73 @@
+use axum::{
+    Router,
+    middleware::from_fn,
+    routing::{delete, get, post},
+};
+
+// Import invoice handler
+use crate::handlers::invoice_handler::{
+    generate_kost_invoices,
+    get_all_invoices,
+    get_invoice_by_id,
+    cancel_invoice,
+};
+
+// Import payment handler
+use crate::handlers::payment_handler::{
+    create_payment,
+    get_all_payments,
+    delete_payment,
+};
+
+// Import auth middleware
+use crate::middlewares::auth_middleware::auth;
+
+// Import permission middleware
+use crate::middlewares::permission_middleware::require_permissions;
+
+pub fn invoice_route() -> Router {
+    Router::new()
+        // GET /api/kosts/{kost_id}/invoices -> Get all invoices of the kost, filter by ?status= and ?period=YYYY-MM
+        .route(
+            "/api/kosts/{kost_id}/invoices",
+            get(get_all_invoices)
+                .layer(require_permissions(&["invoice:view"]))
+        )
+        // POST /api/kosts/{kost_id}/invoices/generate -> Generate the monthly invoices of every active booking
+        .route(
+            "/api/kosts/{kost_id}/invoices/generate",
+            post(generate_kost_invoices)
+                .layer(require_permissions(&["invoice:create"]))
+        )
+        // GET /api/kosts/{kost_id}/invoices/{invoice_id} -> Get invoice by id
+        .route(
+            "/api/kosts/{kost_id}/invoices/{invoice_id}",
+            get(get_invoice_by_id)
+                .layer(require_permissions(&["invoice:view"]))
+        )
+        // POST /api/kosts/{kost_id}/invoices/{invoice_id}/cancel -> Cancel an unpaid invoice
+        .route(
+            "/api/kosts/{kost_id}/invoices/{invoice_id}/cancel",
+            post(cancel_invoice)
+                .layer(require_permissions(&["invoice:update"]))
+        )
+        // POST /api/kosts/{kost_id}/invoices/{invoice_id}/payments -> Record a (part) payment for the invoice
+        .route(
+            "/api/kosts/{kost_id}/invoices/{invoice_id}/payments",
+            post(create_payment)
+                .layer(require_permissions(&["payment:create"]))
+        )
+        // GET /api/kosts/{kost_id}/invoices/{invoice_id}/payments -> Get all payments of the invoice
+        .route(
+            "/api/kosts/{kost_id}/invoices/{invoice_id}/payments",
+            get(get_all_payments)
+                .layer(require_permissions(&["payment:view"]))
+        )
+        // DELETE /api/kosts/{kost_id}/invoices/{invoice_id}/payments/{payment_id} -> Delete a payment recorded by mistake
+        .route(
+            "/api/kosts/{kost_id}/invoices/{invoice_id}/payments/{payment_id}",
+            delete(delete_payment)
+                .layer(require_permissions(&["payment:delete"]))
+        )
+        .layer(from_fn(auth))
+}
@@ -0,0 +1,46 @@
+use axum::{
+    Router,
+    extract::DefaultBodyLimit,
+    middleware::from_fn,
+    routing::{delete, get, post},
+};
+
+// Import kost image handler
+use crate::handlers::kost_image_handler::{
+    upload_kost_image,
+    get_kost_images,
+    delete_kost_image,
+};
+
+// Import kost image schema
+use crate::schemas::kost_image_schema::MAX_IMAGE_BYTES;
+
+// Import auth middleware
+use crate::middlewares::auth_middleware::auth;
+
+// Import permission middleware
+use crate::middlewares::permission_middleware::require_permissions;
+
+pub fn kost_image_route() -> Router {
+    Router::new()
+        // POST /api/kosts/{kost_id}/images -> Upload a kost image as multipart field "image"
+        .route(
+            "/api/kosts/{kost_id}/images",
+            post(upload_kost_image)
+                .layer(DefaultBodyLimit::max(MAX_IMAGE_BYTES + 64 * 1024))
+                .layer(require_permissions(&["kost:update"]))
+        )
+        // GET /api/kosts/{kost_id}/images -> Get all images of the kost
+        .route(
+            "/api/kosts/{kost_id}/images",
+            get(get_kost_images)
+                .layer(require_permissions(&["kost:view"]))
+        )
+        // DELETE /api/kosts/{kost_id}/images/{image_id} -> Delete a kost image
+        .route(
+            "/api/kosts/{kost_id}/images/{image_id}",
+            delete(delete_kost_image)
+                .layer(require_permissions(&["kost:update"]))
+        )
+        .layer(from_fn(auth))
+}
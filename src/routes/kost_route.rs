@@ -1,6 +1,6 @@
 use axum::{
     Router,
-    middleware::{self, from_fn},
+    middleware,
     routing::{delete, get, post, put},
 };
 
@@ -17,7 +17,7 @@ use crate::{handlers::kost_handler::{
 use crate::middlewares::auth_middleware::auth;
 
 // Import permission middleware
-use crate::middlewares::permission_middleware::require_permission_owner;
+use crate::middlewares::permission_middleware::require_permissions;
 
 pub fn kost_route() -> Router {
     Router::new()
@@ -25,7 +25,7 @@ pub fn kost_route() -> Router {
         .route(
             "/api/kosts", 
             post(create_new_kost)
-                .layer(from_fn(require_permission_owner))
+                .layer(require_permissions(&["kost:create"]))
         )
         /*  GET /api/kosts -> 
             get all kost, with guard in the handler, if role == "ADMIN", fetch all kosts data
@@ -34,24 +34,24 @@ pub fn kost_route() -> Router {
         .route(
             "/api/kosts", 
             get(get_all_kosts)
-                .layer(from_fn(require_permission_owner))
+                .layer(require_permissions(&["kost:view"]))
         )
         // GET /api/kosts/{id} -> get kost data by kost id
         .route(
             "/api/kosts/{id}", 
             get(get_kost_by_id)
-                .layer(from_fn(require_permission_owner))
+                .layer(require_permissions(&["kost:view"]))
         )
         // PUT /api/kosts/{id} -> update kost data
         .route(
             "/api/kosts/{id}", 
             put(update_kost)
-                .layer(from_fn(require_permission_owner))
+                .layer(require_permissions(&["kost:update"]))
         )
         .route(
             "/api/kosts/{id}",
             delete(delete_kost)
-                .layer(from_fn(require_permission_owner))
+                .layer(require_permissions(&["kost:delete"]))
         )
         .layer(middleware::from_fn(auth))
 }
\ No newline at end of file
@@ -0,0 +1,89 @@
+use axum::{
+    Router,
+    middleware::from_fn,
+    routing::{delete, get, patch, post, put},
+};
+
+// Import me handler
+use crate::handlers::me_handler::{
+    get_my_bookings,
+    get_my_invoices,
+    get_my_payments,
+    get_me,
+    update_me,
+    change_my_password,
+    delete_me,
+};
+
+// Import booking request handler
+use crate::handlers::booking_request_handler::{
+    create_booking_request,
+    get_my_booking_requests,
+};
+
+// Import owner application handler
+use crate::handlers::owner_application_handler::{
+    create_owner_application,
+    get_my_owner_applications,
+};
+
+// Import auth middleware
+use crate::middlewares::auth_middleware::auth;
+
+// Import permission middleware
+use crate::middlewares::permission_middleware::require_permissions;
+
+pub fn me_route() -> Router {
+    Router::new()
+        // GET /api/me -> Get the profile, role, permissions and owned kost count of the current user
+        .route("/api/me", get(get_me))
+        // PATCH /api/me -> Update the name, email, phone or avatar of the current user
+        .route("/api/me", patch(update_me))
+        // DELETE /api/me -> Delete the account of the current user, needs the password
+        .route("/api/me", delete(delete_me))
+        // PUT /api/me/password -> Change the password, needs the current password
+        .route("/api/me/password", put(change_my_password))
+        // GET /api/me/bookings -> Get the bookings of the current user
+        .route(
+            "/api/me/bookings",
+            get(get_my_bookings)
+                .layer(require_permissions(&["booking:view_own"]))
+        )
+        // GET /api/me/invoices -> Get the invoices of the current user
+        .route(
+            "/api/me/invoices",
+            get(get_my_invoices)
+                .layer(require_permissions(&["invoice:view_own"]))
+        )
+        // GET /api/me/payments -> Get the payment history of the current user
+        .route(
+            "/api/me/payments",
+            get(get_my_payments)
+                .layer(require_permissions(&["payment:view_own"]))
+        )
+        // POST /api/me/booking-requests -> Request an available room
+        .route(
+            "/api/me/booking-requests",
+            post(create_booking_request)
+                .layer(require_permissions(&["booking:request"]))
+        )
+        // GET /api/me/booking-requests -> Get the booking requests of the current user
+        .route(
+            "/api/me/booking-requests",
+            get(get_my_booking_requests)
+                .layer(require_permissions(&["booking:request"]))
+        )
+        // POST /api/me/owner-applications -> Apply to become a kost owner
+        .route(
+            "/api/me/owner-applications",
+            post(create_owner_application)
+                .layer(require_permissions(&["owner_application:create"]))
+        )
+        // GET /api/me/owner-applications -> Get the owner applications of the current user
+        .route(
+            "/api/me/owner-applications",
+            get(get_my_owner_applications)
+                .layer(require_permissions(&["owner_application:create"]))
+        )
+        .layer(from_fn(auth))
+}
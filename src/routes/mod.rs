@@ -1,4 +1,14 @@
 pub mod auth_routes;
 pub mod user_route;
 pub mod kost_route;
-pub mod room_route;
\ No newline at end of file
+pub mod room_route;
+pub mod booking_route;
+pub mod invoice_route;
+pub mod dashboard_route;
+pub mod upload_route;
+pub mod kost_image_route;
+pub mod me_route;
+pub mod public_route;
+pub mod booking_request_route;
+pub mod role_route;
+pub mod owner_application_route;
\ No newline at end of file
@@ -0,0 +1,41 @@
+use axum::{
+    Router,
+    middleware::from_fn,
+    routing::{get, post},
+};
+
+// Import owner application handler
+use crate::handlers::owner_application_handler::{
+    get_owner_applications,
+    approve_owner_application,
+    reject_owner_application,
+};
+
+// Import auth middleware
+use crate::middlewares::auth_middleware::auth;
+
+// Import permission middleware
+use crate::middlewares::permission_middleware::require_permissions;
+
+pub fn owner_application_route() -> Router {
+    Router::new()
+        // GET /api/owner-applications -> Get the owner applications, filter by ?status=
+        .route(
+            "/api/owner-applications",
+            get(get_owner_applications)
+                .layer(require_permissions(&["owner_application:review"]))
+        )
+        // POST /api/owner-applications/{application_id}/approve -> Approve the application and upgrade the user to OWNER
+        .route(
+            "/api/owner-applications/{application_id}/approve",
+            post(approve_owner_application)
+                .layer(require_permissions(&["owner_application:review"]))
+        )
+        // POST /api/owner-applications/{application_id}/reject -> Reject the application
+        .route(
+            "/api/owner-applications/{application_id}/reject",
+            post(reject_owner_application)
+                .layer(require_permissions(&["owner_application:review"]))
+        )
+        .layer(from_fn(auth))
+}
@@ -0,0 +1,19 @@
+use axum::{
+    Router,
+    routing::get,
+};
+
+// Import public handler
+use crate::handlers::public_handler::{
+    get_public_kosts,
+    get_public_kost_by_id,
+};
+
+// Routes for prospective tenants, these do not need a login
+pub fn public_route() -> Router {
+    Router::new()
+        // GET /api/public/kosts -> Search kosts that still have available rooms
+        .route("/api/public/kosts", get(get_public_kosts))
+        // GET /api/public/kosts/{kost_id} -> Get a kost with its available rooms
+        .route("/api/public/kosts/{kost_id}", get(get_public_kost_by_id))
+}
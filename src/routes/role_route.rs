@@ -0,0 +1,96 @@
+use axum::{
+    Router,
+    middleware::from_fn,
+    routing::{delete, get, post},
+};
+
+// Import role handler
+use crate::handlers::role_handler::{
+    get_all_roles,
+    get_role_by_id,
+    create_role,
+    delete_role,
+    grant_role_permission,
+    revoke_role_permission,
+};
+
+// Import permission handler
+use crate::handlers::permission_handler::{
+    get_all_permissions,
+    create_permission,
+    delete_permission,
+};
+
+// Import audit log handler
+use crate::handlers::audit_log_handler::get_audit_logs;
+
+// Import auth middleware
+use crate::middlewares::auth_middleware::auth;
+
+// Import permission middleware
+use crate::middlewares::permission_middleware::require_permissions;
+
+pub fn role_route() -> Router {
+    Router::new()
+        // GET /api/roles -> Get all roles with their permissions
+        .route(
+            "/api/roles",
+            get(get_all_roles)
+                .layer(require_permissions(&["role:view"]))
+        )
+        // POST /api/roles -> Create new role
+        .route(
+            "/api/roles",
+            post(create_role)
+                .layer(require_permissions(&["role:create"]))
+        )
+        // GET /api/roles/{role_id} -> Get role by id
+        .route(
+            "/api/roles/{role_id}",
+            get(get_role_by_id)
+                .layer(require_permissions(&["role:view"]))
+        )
+        // DELETE /api/roles/{role_id} -> Delete a role, except ADMIN, OWNER and MEMBER
+        .route(
+            "/api/roles/{role_id}",
+            delete(delete_role)
+                .layer(require_permissions(&["role:delete"]))
+        )
+        // POST /api/roles/{role_id}/permissions -> Grant a permission to the role
+        .route(
+            "/api/roles/{role_id}/permissions",
+            post(grant_role_permission)
+                .layer(require_permissions(&["role:update"]))
+        )
+        // DELETE /api/roles/{role_id}/permissions/{permission_id} -> Revoke a permission from the role
+        .route(
+            "/api/roles/{role_id}/permissions/{permission_id}",
+            delete(revoke_role_permission)
+                .layer(require_permissions(&["role:update"]))
+        )
+        // GET /api/permissions -> Get all permissions
+        .route(
+            "/api/permissions",
+            get(get_all_permissions)
+                .layer(require_permissions(&["permission:view"]))
+        )
+        // POST /api/permissions -> Create new permission
+        .route(
+            "/api/permissions",
+            post(create_permission)
+                .layer(require_permissions(&["permission:create"]))
+        )
+        // DELETE /api/permissions/{permission_id} -> Delete a permission
+        .route(
+            "/api/permissions/{permission_id}",
+            delete(delete_permission)
+                .layer(require_permissions(&["permission:delete"]))
+        )
+        // GET /api/audit-logs -> Get the audit logs, filter by ?actor_id=, ?target_type=, ?target_id= and ?action=
+        .route(
+            "/api/audit-logs",
+            get(get_audit_logs)
+                .layer(require_permissions(&["audit:view"]))
+        )
+        .layer(from_fn(auth))
+}
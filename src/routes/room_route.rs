@@ -1,6 +1,6 @@
 use axum::{
     Router,
-    middleware::{self, from_fn},
+    middleware::from_fn,
     routing::{delete, get, post, put},
 };
 
@@ -17,7 +17,7 @@ use crate::handlers::room_handler::{
 use crate::middlewares::auth_middleware::auth;
 
 // Import permission middleware
-use crate::middlewares::permission_middleware::require_permission_owner;
+use crate::middlewares::permission_middleware::require_permissions;
 
 // Handler to create new room
 pub fn room_route() -> Router {
@@ -25,30 +25,30 @@ pub fn room_route() -> Router {
         // POST /api/kosts/{id} -> Create a new room for the kost
         .route("/api/kosts/{kost_id}/rooms",
         post(create_room)
-            .layer(from_fn(require_permission_owner))
+            .layer(require_permissions(&["room:create"]))
         )
         // GET /api/kosts/{id} -> Get all rooms
         .route("/api/kosts/{kost_id}/rooms", 
         get(get_all_rooms)
-            .layer(from_fn(require_permission_owner))
+            .layer(require_permissions(&["room:view"]))
         )
         // GET /api/kosts/{kost_id}/rooms/{room_id} => Get room by id
         .route(
             "/api/kosts/{kost_id}/rooms/{room_id}",
             get(get_room_by_id)
-                .layer(from_fn(require_permission_owner)) 
+                .layer(require_permissions(&["room:view"])) 
         )
         // PUT /api/kosts/{kost_id}/rooms/{room_id} => update room data
         .route(
             "/api/kosts/{kost_id}/rooms/{room_id}", 
-            put(update_room))
-                .layer(from_fn(require_permission_owner)
+            put(update_room)
+                .layer(require_permissions(&["room:update"]))
         )
         // DELETE /api/kosts/{kost_id}/rooms/{room_id} => delete room
         .route(
             "/api/kosts/{kost_id}/rooms/{room_id}", 
-            delete(delete_room))
-                .layer(from_fn(require_permission_owner)
+            delete(delete_room)
+                .layer(require_permissions(&["room:delete"]))
         )
         .layer(from_fn(auth))
 }
\ No newline at end of file
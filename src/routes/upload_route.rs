@@ -0,0 +1,22 @@
+use axum::Router;
+use tower_http::services::ServeDir;
+
+// Import app config
+use crate::config::app_config::{self, StorageDriver};
+
+// Import local storage
+use crate::storage::local_storage::LocalStorage;
+
+// Serve the files of the local storage, so image URLs work without a separate web server
+pub fn upload_route() -> Router {
+    match &app_config::get().storage_driver {
+        StorageDriver::Local(config) => {
+            let local = LocalStorage::from_config(config);
+
+            match local.public_path() {
+                Some(path) => Router::new().nest_service(path, ServeDir::new(local.root())),
+                None => Router::new(),
+            }
+        },
+    }
+}
\ No newline at end of file
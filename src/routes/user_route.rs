@@ -1,6 +1,6 @@
 use axum::{
     Router, 
-    middleware::{self, from_fn}, 
+    middleware, 
     routing::{delete, get, post, put},
 };
 
@@ -11,13 +11,17 @@ use crate::handlers::user_handler::{
     get_user_by_id,
     update_user,
     delete_user,
+    change_user_role,
 };
 
+//Import email verification handler
+use crate::handlers::email_verification_handler::resend_verification;
+
 //Import auth middleware
 use crate::middlewares::auth_middleware::auth;
 
 // Import permission middleware
-use  crate::middlewares::permission_middleware::require_permission_admin;
+use crate::middlewares::permission_middleware::require_permissions;
 
 pub fn user_routes() -> Router {
     Router::new()
@@ -25,20 +29,44 @@ pub fn user_routes() -> Router {
         .route(
             "/api/users", 
             get(index)
-                .layer(from_fn(require_permission_admin))
+                .layer(require_permissions(&["user:view"]))
         )
         // POST /api/users -> create new user
-        .route("/api/users", post(store))
+        .route(
+            "/api/users",
+            post(store)
+                .layer(require_permissions(&["user:create"]))
+        )
         // GET /api/users/{id} -> get user by id
         .route(
             "/api/users/{id}", 
             get(get_user_by_id)
-                .layer(from_fn(require_permission_admin))
+                .layer(require_permissions(&["user:view"]))
         )
         // PUT /api/users/{id} -> update user's data
-        .route("/api/users/{id}", put(update_user))
-        // POST /api/users/{id} -> delete user
-        .route("/api/users/{id}", delete(delete_user))
+        .route(
+            "/api/users/{id}",
+            put(update_user)
+                .layer(require_permissions(&["user:update"]))
+        )
+        // DELETE /api/users/{id} -> delete user
+        .route(
+            "/api/users/{id}",
+            delete(delete_user)
+                .layer(require_permissions(&["user:delete"]))
+        )
+        // PUT /api/users/{id}/role -> give the user another role
+        .route(
+            "/api/users/{id}/role",
+            put(change_user_role)
+                .layer(require_permissions(&["user:change_role"]))
+        )
+        // POST /api/users/{id}/verification -> send a new email verification link
+        .route(
+            "/api/users/{id}/verification",
+            post(resend_verification)
+                .layer(require_permissions(&["user:resend_verification"]))
+        )
         // Guard protector for all route above, make sure user must logged in
         .layer(middleware::from_fn(auth))
 }
\ No newline at end of file
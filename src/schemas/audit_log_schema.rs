@@ -0,0 +1,19 @@
+use serde::Deserialize;
+
+use uuid::Uuid;
+use validator::Validate;
+
+// Audit logs per page when the request does not say
+pub const DEFAULT_AUDIT_PER_PAGE: u32 = 50;
+
+#[derive(Deserialize, Validate)]
+pub struct AuditLogQuery {
+    pub actor_id: Option<Uuid>,
+    pub target_type: Option<String>,
+    pub target_id: Option<Uuid>,
+    pub action: Option<String>,
+    #[validate(range(min = 1, message = "Page must be at least 1"))]
+    pub page: Option<u32>,
+    #[validate(range(min = 1, max = 200, message = "Per page must be between 1 and 200"))]
+    pub per_page: Option<u32>,
+}
@@ -0,0 +1,64 @@
+use serde::{
+    Serialize,
+    Deserialize
+};
+
+use chrono::{
+    NaiveDateTime,
+    Utc,
+};
+
+use uuid::Uuid;
+use validator::{Validate, ValidationError};
+use sqlx::Type;
+
+// How long the owner has to answer a request before it expires
+pub const BOOKING_REQUEST_TTL_HOURS: i64 = 72;
+
+#[derive(Deserialize, Validate)]
+#[validate(schema(function = "validate_booking_request_dates", message = "Check out must be after check in"))]
+pub struct BookingRequestNewRequest {
+    pub room_id: Uuid,
+    #[validate(custom(function = "validate_check_in", message = "Check in cannot be in the past"))]
+    pub check_in: NaiveDateTime,
+    pub check_out: Option<NaiveDateTime>,
+    #[validate(length(max = 500, message = "Message cannot be more than 500 characters"))]
+    pub message: Option<String>,
+}
+
+#[derive(Deserialize, Validate)]
+pub struct BookingRequestRejectRequest {
+    #[validate(length(max = 500, message = "Reason cannot be more than 500 characters"))]
+    pub reason: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct BookingRequestQuery {
+    pub status: Option<BookingRequestStatus>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Type, Serialize, Deserialize)]
+#[sqlx(type_name = "ENUM")]
+#[sqlx(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BookingRequestStatus {
+    REQUESTED,
+    APPROVED,
+    REJECTED,
+    EXPIRED,
+}
+
+fn validate_check_in(check_in: &NaiveDateTime) -> Result<(), ValidationError> {
+    if check_in.date() < Utc::now().date_naive() {
+        return Err(ValidationError::new("check_in"));
+    }
+
+    Ok(())
+}
+
+// Same check as the CHECK constraint on Booking_Requests
+fn validate_booking_request_dates(payload: &BookingRequestNewRequest) -> Result<(), ValidationError> {
+    match payload.check_out {
+        Some(check_out) if check_out <= payload.check_in => Err(ValidationError::new("booking_dates")),
+        _ => Ok(()),
+    }
+}
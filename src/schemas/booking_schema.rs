@@ -0,0 +1,89 @@
+use serde::{
+    Serialize,
+    Deserialize
+};
+
+use chrono::{
+    DateTime,
+    NaiveDateTime,
+    Utc,
+};
+
+use uuid::Uuid;
+use validator::{Validate, ValidationError};
+use sqlx::Type;
+
+#[derive(Deserialize, Validate)]
+#[validate(schema(function = "validate_new_booking_dates", message = "Check out must be after check in"))]
+pub struct BookingNewRequest {
+    pub user_id: Uuid,
+    pub check_in: NaiveDateTime,
+    pub check_out: Option<NaiveDateTime>,
+    // Agreed monthly rent, the room price when empty
+    #[validate(range(min = 1, message = "Monthly rent cannot be empty"))]
+    pub monthly_rent: Option<u64>,
+    pub payment_status: Option<PaymentStatus>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BookingNewResponse {
+    pub id: Uuid,
+    pub room_id: Uuid,
+    pub user_id: Uuid,
+    pub check_in: NaiveDateTime,
+    pub check_out: Option<NaiveDateTime>,
+    pub monthly_rent: u64,
+    pub payment_status: PaymentStatus,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+// The payment status is not part of the update, it follows the invoices of the booking
+// and a booking is cancelled or checked out through its own endpoint
+#[derive(Deserialize, Validate)]
+#[validate(schema(function = "validate_update_booking_dates", message = "Check out must be after check in"))]
+pub struct BookingUpdateRequest {
+    pub check_in: NaiveDateTime,
+    pub check_out: Option<NaiveDateTime>,
+    #[validate(range(min = 1, message = "Monthly rent cannot be empty"))]
+    pub monthly_rent: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BookingUpdateResponse {
+    pub id: Uuid,
+    pub room_id: Uuid,
+    pub user_id: Uuid,
+    pub check_in: NaiveDateTime,
+    pub check_out: Option<NaiveDateTime>,
+    pub monthly_rent: u64,
+    pub payment_status: PaymentStatus,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Type, Serialize, Deserialize)]
+#[sqlx(type_name = "ENUM")]
+#[sqlx(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PaymentStatus {
+    PENDING,
+    PAID,
+    OVERDUE,
+    CANCELLED,
+}
+
+// Same check as the CHECK constraint on Bookings, so the client gets a 422 instead of a 500
+fn check_booking_dates(check_in: &NaiveDateTime, check_out: &Option<NaiveDateTime>) -> Result<(), ValidationError> {
+    match check_out {
+        Some(check_out) if check_out <= check_in => Err(ValidationError::new("booking_dates")),
+        _ => Ok(()),
+    }
+}
+
+fn validate_new_booking_dates(payload: &BookingNewRequest) -> Result<(), ValidationError> {
+    check_booking_dates(&payload.check_in, &payload.check_out)
+}
+
+fn validate_update_booking_dates(payload: &BookingUpdateRequest) -> Result<(), ValidationError> {
+    check_booking_dates(&payload.check_in, &payload.check_out)
+}
@@ -0,0 +1,29 @@
+use serde::Serialize;
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize)]
+pub struct DashboardResponse {
+    pub period_start: NaiveDate,
+    pub kosts: Vec<KostSummary>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct KostSummary {
+    pub kost_id: Uuid,
+    pub kost_name: String,
+    pub rooms: RoomCounts,
+    // Occupied rooms in percent of the rooms that are not under maintenance
+    pub occupancy_rate: f64,
+    pub overdue_invoices: i64,
+    pub expected_rent: u64,
+    pub collected_rent: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RoomCounts {
+    pub total: i64,
+    pub available: i64,
+    pub occupied: i64,
+    pub maintenance: i64,
+}
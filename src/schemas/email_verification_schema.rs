@@ -0,0 +1,9 @@
+use serde::Deserialize;
+
+use validator::Validate;
+
+#[derive(Deserialize, Validate)]
+pub struct VerifyEmailRequest {
+    #[validate(length(min = 1, message = "Verification token cannot be empty"))]
+    pub token: String,
+}
@@ -0,0 +1,66 @@
+use serde::{
+    Serialize,
+    Deserialize
+};
+
+use chrono::NaiveDate;
+use validator::{Validate, ValidationError};
+
+use crate::schemas::booking_schema::PaymentStatus;
+
+#[derive(Deserialize, Validate)]
+pub struct InvoiceGenerateRequest {
+    // Billing month in YYYY-MM format, current month when empty
+    #[validate(custom(function = "validate_period", message = "Period must be in YYYY-MM format"))]
+    pub period: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct InvoiceGenerateResponse {
+    pub period_start: NaiveDate,
+    pub generated: u64,
+}
+
+#[derive(Deserialize)]
+pub struct InvoiceQuery {
+    pub status: Option<PaymentStatus>,
+    pub period: Option<String>,
+}
+
+// Helper function to parse YYYY-MM into the first day of the billing month
+pub fn parse_period(period: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(&format!("{}-01", period), "%Y-%m-%d").ok()
+}
+
+fn validate_period(period: &str) -> Result<(), ValidationError> {
+    match parse_period(period) {
+        Some(_) => Ok(()),
+        None => Err(ValidationError::new("period")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_period_returns_first_day_of_month() {
+        assert_eq!(parse_period("2026-02"), NaiveDate::from_ymd_opt(2026, 2, 1));
+        assert_eq!(parse_period("2025-12"), NaiveDate::from_ymd_opt(2025, 12, 1));
+    }
+
+    #[test]
+    fn parse_period_rejects_invalid_months() {
+        assert_eq!(parse_period("2026-13"), None);
+        assert_eq!(parse_period("2026-00"), None);
+        assert_eq!(parse_period("2026-02-15"), None);
+        assert_eq!(parse_period("february"), None);
+        assert_eq!(parse_period(""), None);
+    }
+
+    #[test]
+    fn validate_period_matches_parse_period() {
+        assert!(validate_period("2026-02").is_ok());
+        assert!(validate_period("2026-13").is_err());
+    }
+}
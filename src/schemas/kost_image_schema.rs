@@ -0,0 +1,63 @@
+use serde::Serialize;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+// Largest image that can be uploaded, 5 MB
+pub const MAX_IMAGE_BYTES: usize = 5 * 1024 * 1024;
+
+// Accepted image content types and the extension used for the object key
+pub const ALLOWED_IMAGE_TYPES: [(&str, &str); 3] = [
+    ("image/jpeg", "jpg"),
+    ("image/png", "png"),
+    ("image/webp", "webp"),
+];
+
+// Helper function to get the content type and extension from the leading bytes of the file,
+// the content type the client sends can be anything. None when the file is not an accepted image
+pub fn detect_image_type(bytes: &[u8]) -> Option<(&'static str, &'static str)> {
+    let index = if bytes.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        0
+    } else if bytes.starts_with(&[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A]) {
+        1
+    } else if bytes.len() >= 12 && bytes.starts_with(b"RIFF") && &bytes[8..12] == b"WEBP" {
+        2
+    } else {
+        return None;
+    };
+
+    Some(ALLOWED_IMAGE_TYPES[index])
+}
+
+#[derive(Debug, Serialize)]
+pub struct KostImageResponse {
+    pub id: Uuid,
+    pub kost_id: Uuid,
+    pub object_key: String,
+    pub url: String,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detect_image_type_reads_the_signature() {
+        assert_eq!(detect_image_type(&[0xFF, 0xD8, 0xFF, 0xE0, 0x00]), Some(("image/jpeg", "jpg")));
+        assert_eq!(
+            detect_image_type(&[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, 0x00]),
+            Some(("image/png", "png"))
+        );
+        assert_eq!(detect_image_type(b"RIFF\x24\x00\x00\x00WEBPVP8 "), Some(("image/webp", "webp")));
+    }
+
+    #[test]
+    fn detect_image_type_rejects_other_files() {
+        assert_eq!(detect_image_type(b""), None);
+        assert_eq!(detect_image_type(b"GIF89a"), None);
+        assert_eq!(detect_image_type(b"<svg xmlns=\"http://www.w3.org/2000/svg\">"), None);
+        assert_eq!(detect_image_type(b"RIFF\x24\x00\x00\x00WAVEfmt "), None);
+        assert_eq!(detect_image_type(b"RIFF"), None);
+    }
+}
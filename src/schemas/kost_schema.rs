@@ -18,6 +18,9 @@ pub struct KostNewRequest {
     pub kost_contact: String,
     #[validate(length(min = 1, message = "Kost description cannot be empty"))]
     pub kost_desc: String,
+    // Days after the due date before an unpaid invoice becomes OVERDUE
+    #[validate(range(max = 31, message = "Grace period cannot be more than 31 days"))]
+    pub grace_period_days: Option<u32>,
 }
 
 #[derive(Debug, Serialize)]
@@ -28,6 +31,7 @@ pub struct KostNewResponse {
     pub kost_address: String,
     pub kost_contact: String,
     pub kost_desc: String,
+    pub grace_period_days: u32,
     pub created_at: Option<DateTime<Utc>>,
     pub updated_at: Option<DateTime<Utc>>
 }
@@ -41,6 +45,9 @@ pub struct KostUpdateRequest {
     pub kost_contact: String,
     #[validate(length(min = 1, message = "Kost description cannot be empty"))]
     pub kost_desc: String,
+    // Days after the due date before an unpaid invoice becomes OVERDUE
+    #[validate(range(max = 31, message = "Grace period cannot be more than 31 days"))]
+    pub grace_period_days: Option<u32>,
 }
 
 #[derive(Serialize)]
@@ -51,6 +58,10 @@ pub struct KostUpdateResponse {
     pub kost_address: String,
     pub kost_contact: String,
     pub kost_desc: String,
+    pub grace_period_days: u32,
     pub created_at: Option<DateTime<Utc>>,
     pub update_at: Option<DateTime<Utc>>,
-}
\ No newline at end of file
+}
+
+// Grace period used when the kost does not set one
+pub const DEFAULT_GRACE_PERIOD_DAYS: u32 = 3;
@@ -21,4 +21,7 @@ pub struct UserResponse {
 pub struct LoginResponse {
     pub user: UserResponse,
     pub token: String,
+    pub refresh_token: String,
+    // Seconds until the access token expires
+    pub expires_in: i64,
 }
\ No newline at end of file
@@ -0,0 +1,80 @@
+use serde::{
+    Serialize,
+    Deserialize
+};
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+use uuid::Uuid;
+use validator::{Validate, ValidateUrl, ValidationError};
+
+// Fields left out are not changed, an empty phone or avatar_url removes it
+#[derive(Deserialize, Validate)]
+pub struct MeUpdateRequest {
+    #[validate(length(min = 3, message = "Name must be 3 characters or more"))]
+    pub name: Option<String>,
+    #[validate(email(message = "Email is not valid"))]
+    pub email: Option<String>,
+    #[validate(custom(function = "validate_phone", message = "Phone must be 8 to 30 digits, spaces, dashes or a leading +"))]
+    pub phone: Option<String>,
+    #[validate(custom(function = "validate_avatar_url", message = "Avatar must be a valid URL of at most 500 characters"))]
+    pub avatar_url: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MeResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub email: String,
+    pub email_verified_at: Option<NaiveDateTime>,
+    pub phone: Option<String>,
+    pub avatar_url: Option<String>,
+    pub role: String,
+    // Permissions of the role right now, the token may still carry older ones until it is refreshed
+    pub permissions: Vec<String>,
+    pub owned_kost_count: i64,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Deserialize, Validate)]
+pub struct ChangePasswordRequest {
+    #[validate(length(min = 1, message = "Current password cannot be empty"))]
+    pub current_password: String,
+    #[validate(length(min = 6, message = "Password must be 6 characters"))]
+    pub new_password: String,
+}
+
+#[derive(Deserialize, Validate)]
+pub struct DeleteAccountRequest {
+    #[validate(length(min = 1, message = "Password cannot be empty"))]
+    pub password: String,
+}
+
+fn validate_phone(phone: &str) -> Result<(), ValidationError> {
+    if phone.is_empty() {
+        return Ok(());
+    }
+
+    let digits = phone.strip_prefix('+').unwrap_or(phone);
+    let valid = (8..=30).contains(&phone.len())
+        && digits.chars().all(|c| c.is_ascii_digit() || c == ' ' || c == '-')
+        && digits.chars().any(|c| c.is_ascii_digit());
+
+    if !valid {
+        return Err(ValidationError::new("phone"));
+    }
+
+    Ok(())
+}
+
+fn validate_avatar_url(avatar_url: &str) -> Result<(), ValidationError> {
+    if avatar_url.is_empty() {
+        return Ok(());
+    }
+
+    if avatar_url.len() > 500 || !avatar_url.validate_url() {
+        return Err(ValidationError::new("avatar_url"));
+    }
+
+    Ok(())
+}
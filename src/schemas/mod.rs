@@ -2,4 +2,18 @@ pub mod register_schema;
 pub mod login_schema;
 pub mod user_schema;
 pub mod kost_schema;
-pub mod room_schema;
\ No newline at end of file
+pub mod room_schema;
+pub mod booking_schema;
+pub mod invoice_schema;
+pub mod payment_schema;
+pub mod dashboard_schema;
+pub mod kost_image_schema;
+pub mod public_schema;
+pub mod booking_request_schema;
+pub mod role_schema;
+pub mod audit_log_schema;
+pub mod token_schema;
+pub mod password_schema;
+pub mod email_verification_schema;
+pub mod owner_application_schema;
+pub mod me_schema;
\ No newline at end of file
@@ -0,0 +1,39 @@
+use serde::{
+    Serialize,
+    Deserialize
+};
+
+use validator::Validate;
+use sqlx::Type;
+
+#[derive(Deserialize, Validate)]
+pub struct OwnerApplicationNewRequest {
+    #[validate(length(min = 3, max = 150, message = "Business name must be between 3 and 150 characters"))]
+    pub business_name: String,
+    #[validate(length(min = 5, max = 255, message = "Business address must be between 5 and 255 characters"))]
+    pub business_address: String,
+    #[validate(length(min = 8, max = 30, message = "Phone must be between 8 and 30 characters"))]
+    pub phone: String,
+    #[validate(length(max = 1000, message = "Description cannot be more than 1000 characters"))]
+    pub description: Option<String>,
+}
+
+#[derive(Deserialize, Validate)]
+pub struct OwnerApplicationRejectRequest {
+    #[validate(length(max = 500, message = "Reason cannot be more than 500 characters"))]
+    pub reason: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct OwnerApplicationQuery {
+    pub status: Option<OwnerApplicationStatus>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Type, Serialize, Deserialize)]
+#[sqlx(type_name = "ENUM")]
+#[sqlx(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OwnerApplicationStatus {
+    PENDING,
+    APPROVED,
+    REJECTED,
+}
@@ -0,0 +1,17 @@
+use serde::Deserialize;
+
+use validator::Validate;
+
+#[derive(Deserialize, Validate)]
+pub struct ForgotPasswordRequest {
+    #[validate(email(message = "Email is not valid"))]
+    pub email: String,
+}
+
+#[derive(Deserialize, Validate)]
+pub struct ResetPasswordRequest {
+    #[validate(length(min = 1, message = "Reset token cannot be empty"))]
+    pub token: String,
+    #[validate(length(min = 6, message = "Password must be 6 characters"))]
+    pub password: String,
+}
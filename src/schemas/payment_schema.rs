@@ -0,0 +1,63 @@
+use serde::{
+    Serialize,
+    Deserialize
+};
+
+use chrono::{
+    DateTime,
+    NaiveDateTime,
+    Utc,
+};
+
+use uuid::Uuid;
+use validator::{Validate, ValidationError};
+use sqlx::Type;
+
+use crate::schemas::booking_schema::PaymentStatus;
+
+#[derive(Deserialize, Validate)]
+#[validate(schema(function = "validate_payment_reference", message = "Reference is required for bank transfer"))]
+pub struct PaymentNewRequest {
+    #[validate(range(min = 1, message = "Payment amount cannot be empty"))]
+    pub amount: u64,
+    pub method: PaymentMethod,
+    #[validate(length(max = 100, message = "Reference cannot be more than 100 characters"))]
+    pub reference: Option<String>,
+    // Time the money was received, now when empty
+    pub paid_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PaymentNewResponse {
+    pub id: Uuid,
+    pub invoice_id: Uuid,
+    pub amount: u64,
+    pub method: PaymentMethod,
+    pub reference: Option<String>,
+    pub paid_at: NaiveDateTime,
+    pub recorded_by: Option<Uuid>,
+    pub invoice_status: PaymentStatus,
+    pub invoice_outstanding: u64,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Type, Serialize, Deserialize)]
+#[sqlx(type_name = "ENUM")]
+#[sqlx(rename_all = "SCREAMING_SNAKE_CASE")]
+#[allow(non_camel_case_types)]
+pub enum PaymentMethod {
+    CASH,
+    BANK_TRANSFER,
+}
+
+fn validate_payment_reference(payload: &PaymentNewRequest) -> Result<(), ValidationError> {
+    let has_reference = payload.reference
+        .as_deref()
+        .is_some_and(|r| !r.trim().is_empty());
+
+    match payload.method {
+        PaymentMethod::BANK_TRANSFER if !has_reference => Err(ValidationError::new("payment_reference")),
+        _ => Ok(()),
+    }
+}
@@ -0,0 +1,90 @@
+use serde::{
+    Serialize,
+    Deserialize
+};
+
+use uuid::Uuid;
+use validator::{Validate, ValidationError};
+
+// Kosts per page when the request does not say
+pub const DEFAULT_PER_PAGE: u32 = 20;
+
+#[derive(Deserialize, Validate)]
+#[validate(schema(function = "validate_price_range", message = "Min price cannot be more than max price"))]
+pub struct PublicKostQuery {
+    // Matched against the kost address
+    #[validate(length(max = 100, message = "City cannot be more than 100 characters"))]
+    pub city: Option<String>,
+    // Matched against the kost name, address and description
+    #[validate(length(max = 100, message = "Search text cannot be more than 100 characters"))]
+    pub q: Option<String>,
+    pub min_price: Option<u64>,
+    pub max_price: Option<u64>,
+    #[validate(range(min = 1, message = "Page must be at least 1"))]
+    pub page: Option<u32>,
+    #[validate(range(min = 1, max = 100, message = "Per page must be between 1 and 100"))]
+    pub per_page: Option<u32>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PublicKostResponse {
+    pub id: Uuid,
+    pub kost_name: String,
+    pub kost_address: String,
+    pub kost_contact: String,
+    pub kost_desc: String,
+    pub images: Vec<PublicKostImage>,
+    pub available_rooms: Vec<PublicRoom>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PublicKostImage {
+    pub id: Uuid,
+    pub url: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PublicRoom {
+    pub id: Uuid,
+    pub room_number: u32,
+    pub monthly_rent: u64,
+    pub deposit: Option<u64>,
+    pub currency: String,
+}
+
+// Helper function to turn user text into a LIKE pattern, so % and _ are matched literally
+pub fn like_pattern(text: &str) -> String {
+    let escaped = text
+        .trim()
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+
+    format!("%{}%", escaped)
+}
+
+fn validate_price_range(query: &PublicKostQuery) -> Result<(), ValidationError> {
+    match (query.min_price, query.max_price) {
+        (Some(min), Some(max)) if min > max => Err(ValidationError::new("price_range")),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn like_pattern_wraps_trimmed_text() {
+        assert_eq!(like_pattern("  kost melati "), "%kost melati%");
+        assert_eq!(like_pattern(""), "%%");
+    }
+
+    #[test]
+    fn like_pattern_escapes_wildcards() {
+        assert_eq!(like_pattern("50%"), "%50\\%%");
+        assert_eq!(like_pattern("kost_a"), "%kost\\_a%");
+        assert_eq!(like_pattern("a\\b"), "%a\\\\b%");
+        assert_eq!(like_pattern("\\%"), "%\\\\\\%%");
+    }
+}
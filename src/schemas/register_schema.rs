@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
-use validator::Validate;
+use validator::{Validate, ValidationError};
 use uuid::Uuid;
 
 #[derive(Deserialize, Validate, Debug)]
@@ -11,6 +11,11 @@ pub struct RegisterRequest {
     pub email: String,
     #[validate(length(min = 6, message = "Password must be 6 characters"))]
     pub password: String,
+    // Registration is always MEMBER, asking for OWNER gets an error instead of a MEMBER account
+    #[validate(custom(
+        function = "reject_owner",
+        message = "Owner accounts cannot be registered, register and apply through POST /api/me/owner-applications to become an owner"
+    ))]
     pub role: Option<RegRole>,
 }
 
@@ -24,8 +29,44 @@ pub struct RegisterResponse {
     pub updated_at: Option<DateTime<Utc>>,
 }
 
-#[derive(Deserialize, Debug)]
+// Role an admin can give when creating a user, registration is always MEMBER
+// and becomes OWNER through an owner application
+#[derive(Serialize, Deserialize, Debug)]
 pub enum RegRole {
     OWNER,
     MEMBER,
-}
\ No newline at end of file
+}
+
+fn reject_owner(role: &RegRole) -> Result<(), ValidationError> {
+    match role {
+        RegRole::OWNER => Err(ValidationError::new("role")),
+        RegRole::MEMBER => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(role: Option<RegRole>) -> RegisterRequest {
+        RegisterRequest {
+            name: "Budi".to_string(),
+            email: "budi@example.com".to_string(),
+            password: "secret123".to_string(),
+            role,
+        }
+    }
+
+    #[test]
+    fn member_or_no_role_is_accepted() {
+        assert!(request(None).validate().is_ok());
+        assert!(request(Some(RegRole::MEMBER)).validate().is_ok());
+    }
+
+    #[test]
+    fn owner_role_is_rejected() {
+        let errors = request(Some(RegRole::OWNER)).validate().unwrap_err();
+
+        assert!(errors.field_errors().contains_key("role"));
+    }
+}
@@ -0,0 +1,69 @@
+use serde::{
+    Serialize,
+    Deserialize
+};
+
+use uuid::Uuid;
+use validator::{Validate, ValidationError};
+
+// Import role models
+use crate::models::role::Permission;
+
+// Roles the application depends on, these can never be deleted
+pub const SYSTEM_ROLES: [&str; 3] = ["ADMIN", "OWNER", "MEMBER"];
+
+#[derive(Deserialize, Validate)]
+pub struct RoleNewRequest {
+    #[validate(
+        length(min = 2, max = 50, message = "Role name must be between 2 and 50 characters"),
+        custom(function = "validate_role_name", message = "Role name can only contain uppercase letters, numbers and underscores")
+    )]
+    pub name: String,
+}
+
+#[derive(Deserialize, Validate)]
+pub struct PermissionNewRequest {
+    #[validate(
+        length(min = 3, max = 50, message = "Permission name must be between 3 and 50 characters"),
+        custom(function = "validate_permission_name", message = "Permission name must be in resource:action format, e.g. room:view")
+    )]
+    pub name: String,
+}
+
+#[derive(Deserialize, Validate)]
+pub struct RolePermissionRequest {
+    pub permission_id: Uuid,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RoleResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub is_system: bool,
+    pub permissions: Vec<Permission>,
+}
+
+// Helper function to check if the role is one of the system roles
+pub fn is_system_role(name: &str) -> bool {
+    SYSTEM_ROLES.contains(&name)
+}
+
+fn validate_role_name(name: &str) -> Result<(), ValidationError> {
+    if !name.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_') {
+        return Err(ValidationError::new("role_name"));
+    }
+
+    Ok(())
+}
+
+fn validate_permission_name(name: &str) -> Result<(), ValidationError> {
+    let valid_part = |part: &str| {
+        !part.is_empty()
+            && part.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+    };
+
+    match name.split_once(':') {
+        Some((resource, action)) if valid_part(resource) && valid_part(action) => Ok(()),
+        _ => Err(ValidationError::new("permission_name")),
+    }
+}
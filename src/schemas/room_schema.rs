@@ -1,6 +1,7 @@
 use serde::{
     Serialize,
-    Deserialize
+    Deserialize,
+    Deserializer,
 };
 
 use chrono::{
@@ -9,7 +10,7 @@ use chrono::{
 };
 
 use uuid::Uuid;
-use validator::Validate;
+use validator::{Validate, ValidationError};
 use sqlx::Type;
 
 #[derive(Deserialize, Validate)]
@@ -17,6 +18,11 @@ pub struct RoomNewRequest {
     #[validate(range(min = 1, message = "Room number cannot be empty"))]
     pub room_number: u32,
     pub room_vacancy: RoomStatus,
+    #[validate(range(min = 1, message = "Monthly rent cannot be empty"))]
+    pub monthly_rent: u64,
+    pub deposit: Option<u64>,
+    #[validate(custom(function = "validate_currency", message = "Currency must be a 3 letter ISO code, e.g. IDR"))]
+    pub currency: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -25,6 +31,9 @@ pub struct RoomNewResponse {
     pub kost_id: Uuid,
     pub room_number: u32,
     pub room_vacancy: RoomStatus,
+    pub monthly_rent: u64,
+    pub deposit: Option<u64>,
+    pub currency: String,
     pub created_at: Option<DateTime<Utc>>,
     pub updated_at: Option<DateTime<Utc>>,
 }
@@ -34,6 +43,14 @@ pub struct RoomUpdateRequest {
     #[validate(range(min = 1, message = "Room number cannot be empty"))]
     pub room_number: u32,
     pub room_vacancy: RoomStatus,
+    // Price fields keep the current value when they are left out
+    #[validate(range(min = 1, message = "Monthly rent cannot be empty"))]
+    pub monthly_rent: Option<u64>,
+    // Left out keeps the current deposit, null removes it
+    #[serde(default, deserialize_with = "present")]
+    pub deposit: Option<Option<u64>>,
+    #[validate(custom(function = "validate_currency", message = "Currency must be a 3 letter ISO code, e.g. IDR"))]
+    pub currency: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -42,6 +59,9 @@ pub struct RoomUpdateResponse {
     pub kost_id: Uuid,
     pub room_number: u32,
     pub room_vacancy: RoomStatus,
+    pub monthly_rent: u64,
+    pub deposit: Option<u64>,
+    pub currency: String,
     pub created_at: Option<DateTime<Utc>>,
     pub updated_at: Option<DateTime<Utc>>,
 }
@@ -55,5 +75,58 @@ pub enum RoomStatus {
     MAINTENANCE,
 }
 
+// Currency used when the room price has no currency
+pub const DEFAULT_CURRENCY: &str = "IDR";
 
+// Tell a field that is left out (None) from one that is null (Some(None))
+fn present<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
+
+fn validate_currency(currency: &str) -> Result<(), ValidationError> {
+    if currency.len() == 3 && currency.chars().all(|c| c.is_ascii_uppercase()) {
+        Ok(())
+    } else {
+        Err(ValidationError::new("currency"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn update_request(body: &str) -> RoomUpdateRequest {
+        serde_json::from_str(body).unwrap()
+    }
+
+    #[test]
+    fn price_fields_left_out_are_none() {
+        let payload = update_request(r#"{"room_number": 1, "room_vacancy": "AVAILABLE"}"#);
 
+        assert_eq!(payload.monthly_rent, None);
+        assert_eq!(payload.deposit, None);
+        assert_eq!(payload.currency, None);
+        assert!(payload.validate().is_ok());
+    }
+
+    #[test]
+    fn null_deposit_is_told_apart_from_a_missing_one() {
+        let payload = update_request(r#"{"room_number": 1, "room_vacancy": "AVAILABLE", "deposit": null}"#);
+        assert_eq!(payload.deposit, Some(None));
+
+        let payload = update_request(r#"{"room_number": 1, "room_vacancy": "AVAILABLE", "deposit": 500000}"#);
+        assert_eq!(payload.deposit, Some(Some(500000)));
+    }
+
+    #[test]
+    fn zero_monthly_rent_is_rejected() {
+        let payload = update_request(r#"{"room_number": 1, "room_vacancy": "AVAILABLE", "monthly_rent": 0}"#);
+        let errors = payload.validate().unwrap_err();
+
+        assert!(errors.field_errors().contains_key("monthly_rent"));
+    }
+}
@@ -0,0 +1,20 @@
+use serde::{
+    Serialize,
+    Deserialize
+};
+
+use validator::Validate;
+
+#[derive(Deserialize, Validate)]
+pub struct RefreshTokenRequest {
+    #[validate(length(min = 1, message = "Refresh token cannot be empty"))]
+    pub refresh_token: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RefreshTokenResponse {
+    pub token: String,
+    pub refresh_token: String,
+    // Seconds until the access token expires
+    pub expires_in: i64,
+}
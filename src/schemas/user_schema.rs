@@ -2,7 +2,7 @@ use serde::{
     Serialize,
     Deserialize
 };
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDateTime, Utc};
 use validator::Validate;
 use uuid::Uuid;
 
@@ -25,6 +25,7 @@ pub struct UserNewResponse {
     pub id: Uuid,
     pub name: String,
     pub email: String,
+    pub email_verified_at: Option<NaiveDateTime>,
     pub role_id: Uuid,
     pub created_at: Option<DateTime<Utc>>,
     pub updated_at: Option<DateTime<Utc>>,
@@ -38,3 +39,8 @@ pub struct UserUpdateRequest {
     pub email: String,
     pub password: Option<String>
 }
+
+#[derive(Deserialize, Validate)]
+pub struct UserRoleRequest {
+    pub role_id: Uuid,
+}
@@ -0,0 +1,74 @@
+use std::{io, path::{Component, Path, PathBuf}};
+
+use axum::body::Bytes;
+
+use crate::config::app_config::LocalStorageConfig;
+use crate::storage::{ObjectStorage, StorageFuture};
+
+// Storage that keeps the objects in a directory served by the app
+pub struct LocalStorage {
+    root: PathBuf,
+    public_url: String,
+}
+
+impl LocalStorage {
+    pub fn new(root: impl Into<PathBuf>, public_url: impl Into<String>) -> Self {
+        Self {
+            root: root.into(),
+            public_url: public_url.into().trim_end_matches('/').to_string(),
+        }
+    }
+
+    pub fn from_config(config: &LocalStorageConfig) -> Self {
+        Self::new(config.upload_dir.clone(), config.public_url.clone())
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    // Path the objects are served under, None when the public URL points to another host
+    pub fn public_path(&self) -> Option<&str> {
+        self.public_url
+            .starts_with('/')
+            .then_some(self.public_url.as_str())
+    }
+
+    // Resolve the key inside the root, keys that try to leave the root are rejected
+    fn path(&self, key: &str) -> io::Result<PathBuf> {
+        let relative = Path::new(key);
+
+        if !relative.components().all(|c| matches!(c, Component::Normal(_))) {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "Invalid object key"));
+        }
+
+        Ok(self.root.join(relative))
+    }
+}
+
+impl ObjectStorage for LocalStorage {
+    fn put<'a>(&'a self, key: &'a str, _content_type: &'a str, bytes: Bytes) -> StorageFuture<'a, ()> {
+        Box::pin(async move {
+            let path = self.path(key)?;
+
+            if let Some(parent) = path.parent() {
+                tokio::fs::create_dir_all(parent).await?;
+            }
+
+            tokio::fs::write(path, bytes).await
+        })
+    }
+
+    fn delete<'a>(&'a self, key: &'a str) -> StorageFuture<'a, ()> {
+        Box::pin(async move {
+            match tokio::fs::remove_file(self.path(key)?).await {
+                Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+                _ => Ok(()),
+            }
+        })
+    }
+
+    fn url(&self, key: &str) -> String {
+        format!("{}/{}", self.public_url, key)
+    }
+}
@@ -0,0 +1,32 @@
+use std::{future::Future, io, pin::Pin, sync::Arc};
+
+use axum::body::Bytes;
+
+use crate::config::app_config::{AppConfig, StorageDriver};
+
+pub mod local_storage;
+
+// Boxed future, so the storage can be used behind a trait object
+pub type StorageFuture<'a, T> = Pin<Box<dyn Future<Output = io::Result<T>> + Send + 'a>>;
+
+// Storage shared with the handlers through Extension
+pub type SharedStorage = Arc<dyn ObjectStorage>;
+
+// Backend for uploaded files, implemented by the local filesystem now and an S3 compatible bucket later
+pub trait ObjectStorage: Send + Sync {
+    // Save the object under the key, overwriting an existing one
+    fn put<'a>(&'a self, key: &'a str, content_type: &'a str, bytes: Bytes) -> StorageFuture<'a, ()>;
+
+    // Remove the object, removing a missing object is not an error
+    fn delete<'a>(&'a self, key: &'a str) -> StorageFuture<'a, ()>;
+
+    // Public URL of the object
+    fn url(&self, key: &str) -> String;
+}
+
+// Build the storage from the config, only the local driver is supported for now
+pub fn from_config(config: &AppConfig) -> SharedStorage {
+    match &config.storage_driver {
+        StorageDriver::Local(local) => Arc::new(local_storage::LocalStorage::from_config(local)),
+    }
+}
\ No newline at end of file
@@ -0,0 +1,27 @@
+use serde_json::Value;
+use sqlx::MySqlExecutor;
+use uuid::Uuid;
+
+// Helper function to record who changed what, run it in the same transaction as the change
+pub async fn record_audit<'e>(
+    executor: impl MySqlExecutor<'e>,
+    actor_id: Option<Uuid>,
+    action: &str,
+    target_type: &str,
+    target_id: Option<Uuid>,
+    details: Value,
+) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        "INSERT INTO Audit_Logs (id, actor_id, action, target_type, target_id, details) VALUES (?, ?, ?, ?, ?, ?)",
+        Uuid::new_v4(),
+        actor_id,
+        action,
+        target_type,
+        target_id,
+        details
+    )
+    .execute(executor)
+    .await?;
+
+    Ok(())
+}
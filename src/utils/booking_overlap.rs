@@ -0,0 +1,47 @@
+use chrono::NaiveDateTime;
+use serde_json::json;
+use sqlx::MySqlExecutor;
+use uuid::Uuid;
+
+// Import app error
+use crate::utils::error::AppError;
+
+// Helper function to find the non-cancelled bookings of the room that overlap the given stay,
+// a booking without check out is treated as open ended
+pub async fn find_overlapping_bookings<'e>(
+    executor: impl MySqlExecutor<'e>,
+    room_id: Uuid,
+    check_in: NaiveDateTime,
+    check_out: Option<NaiveDateTime>,
+    exclude_booking_id: Option<Uuid>,
+) -> Result<Vec<Uuid>, sqlx::Error> {
+    let exclude_booking_id = exclude_booking_id.unwrap_or_else(Uuid::nil);
+
+    sqlx::query_scalar!(
+        r#"
+        SELECT id AS "id: Uuid"
+        FROM Bookings
+        WHERE room_id = ?
+            AND id != ?
+            AND payment_status != 'CANCELLED'
+            AND (? IS NULL OR check_in < ?)
+            AND (check_out IS NULL OR check_out > ?)
+        ORDER BY check_in ASC
+        "#,
+        room_id,
+        exclude_booking_id,
+        check_out,
+        check_out,
+        check_in
+    )
+    .fetch_all(executor)
+    .await
+}
+
+// Helper function to answer overlapping bookings with a 409 that lists them
+pub fn overlap_error(conflicts: Vec<Uuid>) -> AppError {
+    AppError::ConflictWith(
+        "Booking dates overlap with existing bookings".to_string(),
+        json!({ "conflicting_booking_ids": conflicts }),
+    )
+}
@@ -0,0 +1,44 @@
+use chrono::{Duration, Utc};
+use sqlx::MySqlExecutor;
+use uuid::Uuid;
+
+use crate::config::app_config;
+use crate::mailer::{send_in_background, Email, SharedMailer};
+use crate::utils::secure_token::{hash_token, random_token};
+
+// Helper function to store a new email verification token for the user, returns the token to email
+pub async fn issue_verification_token<'e>(
+    executor: impl MySqlExecutor<'e>,
+    user_id: Uuid,
+) -> Result<String, sqlx::Error> {
+    let token = random_token();
+    let expires_at = Utc::now().naive_utc() + Duration::hours(app_config::get().email_verification_hours);
+
+    sqlx::query!(
+        "INSERT INTO Email_Verifications (id, user_id, token_hash, expires_at) VALUES (?, ?, ?, ?)",
+        Uuid::new_v4(),
+        user_id,
+        hash_token(&token),
+        expires_at
+    )
+    .execute(executor)
+    .await?;
+
+    Ok(token)
+}
+
+// Helper function to email the verification link, sent in the background
+pub fn send_verification_email(mailer: SharedMailer, name: &str, email: &str, token: &str) {
+    let config = app_config::get();
+    let link = format!("{}/verify-email?token={}", config.frontend_url, token);
+
+    send_in_background(mailer, Email {
+        to: email.to_string(),
+        subject: "Verify your email".to_string(),
+        body: format!(
+            "Hi {},\n\nPlease confirm your email address to activate your Kost Management account:\n\n{}\n\n\
+            The link expires in {} hours. If you did not create an account, you can ignore this email.",
+            name, link, config.email_verification_hours
+        ),
+    });
+}
@@ -0,0 +1,164 @@
+use std::collections::HashMap;
+
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+
+use serde_json::{
+    json,
+    Value,
+};
+
+use validator::ValidationErrors;
+
+// Import API Response
+use crate::utils::response::ApiResponse;
+
+// Error returned by handlers, sent to the client in the usual ApiResponse shape.
+// Database errors are only logged, the client gets a generic message and never the SQL text
+#[derive(Debug)]
+pub enum AppError {
+    BadRequest(String),
+    Unauthorized(String),
+    Forbidden(String),
+    NotFound(String),
+    Conflict(String),
+    // Conflict that names what it conflicts with, sent as the data of the response
+    ConflictWith(String, Value),
+    // Messages per field, sent as the data of the response
+    Validation(HashMap<String, Vec<String>>),
+    Database(sqlx::Error),
+    Internal(String),
+    // Any other status, e.g. 413 and 415 for uploads or the status of a multipart rejection
+    Status(StatusCode, String),
+}
+
+// Result of a handler that answers with the usual ApiResponse
+pub type ApiResult = Result<(StatusCode, Json<ApiResponse<Value>>), AppError>;
+
+impl AppError {
+    // Validation error for a single field, for checks the schema cannot do
+    pub fn field(field: &str, message: &str) -> Self {
+        let mut errors = HashMap::new();
+        errors.insert(field.to_string(), vec![message.to_string()]);
+
+        AppError::Validation(errors)
+    }
+}
+
+impl IntoResponse for AppError {
+    fn into_response(self) -> Response {
+        let (status, body) = match self {
+            // Send 400 response Bad Request
+            AppError::BadRequest(message) => (StatusCode::BAD_REQUEST, ApiResponse::error(&message)),
+            // Send 401 response Unauthorized
+            AppError::Unauthorized(message) => (StatusCode::UNAUTHORIZED, ApiResponse::error(&message)),
+            // Send 403 response Forbidden
+            AppError::Forbidden(message) => (StatusCode::FORBIDDEN, ApiResponse::error(&message)),
+            // Send 404 response Not Found
+            AppError::NotFound(message) => (StatusCode::NOT_FOUND, ApiResponse::error(&message)),
+            // Send 409 response Conflict
+            AppError::Conflict(message) => (StatusCode::CONFLICT, ApiResponse::error(&message)),
+            AppError::ConflictWith(message, data) => (
+                StatusCode::CONFLICT,
+                ApiResponse {
+                    status: false,
+                    message,
+                    data: Some(data),
+                },
+            ),
+            // Send 422 response Unprocessable Entity
+            AppError::Validation(errors) => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                ApiResponse {
+                    status: false,
+                    message: "Failed to validate the request".to_string(),
+                    data: Some(json!(errors)),
+                },
+            ),
+            // Send 500 response Internal Server Error
+            AppError::Database(e) => {
+                eprintln!("Database error: {}", e);
+                (StatusCode::INTERNAL_SERVER_ERROR, ApiResponse::error("Internal server error"))
+            },
+            AppError::Internal(message) => (StatusCode::INTERNAL_SERVER_ERROR, ApiResponse::error(&message)),
+            AppError::Status(status, message) => (status, ApiResponse::error(&message)),
+        };
+
+        (status, Json::<ApiResponse<Value>>(body)).into_response()
+    }
+}
+
+impl From<sqlx::Error> for AppError {
+    fn from(e: sqlx::Error) -> Self {
+        match e {
+            sqlx::Error::RowNotFound => AppError::NotFound("Resource is not found".to_string()),
+            sqlx::Error::Database(ref db) if db.is_unique_violation() => {
+                AppError::Conflict("Resource already exists".to_string())
+            },
+            sqlx::Error::Database(ref db) if db.is_foreign_key_violation() => {
+                AppError::Conflict("Resource conflicts with related data".to_string())
+            },
+            e => AppError::Database(e),
+        }
+    }
+}
+
+impl From<ValidationErrors> for AppError {
+    fn from(e: ValidationErrors) -> Self {
+        let mut field_errors: HashMap<String, Vec<String>> = HashMap::new();
+
+        for (field, errors) in e.field_errors() {
+            let messages = errors
+                .iter()
+                .filter_map(|e| e.message.as_ref())
+                .map(|m| m.to_string())
+                .collect::<Vec<String>>();
+
+            field_errors.insert(field.to_string(), messages);
+        }
+
+        AppError::Validation(field_errors)
+    }
+}
+
+// Replace the generic messages of the sqlx conversion with ones that name the resource
+pub trait DbResultExt<T> {
+    // Missing row becomes a 404 with the message
+    fn or_not_found(self, message: &str) -> Result<T, AppError>;
+
+    // Unique constraint violation becomes a 409 with the message
+    fn or_conflict(self, message: &str) -> Result<T, AppError>;
+
+    // Foreign key violation, e.g. deleting a row that is still referenced, becomes a 409 with the message
+    fn or_referenced(self, message: &str) -> Result<T, AppError>;
+}
+
+impl<T> DbResultExt<T> for Result<T, sqlx::Error> {
+    fn or_not_found(self, message: &str) -> Result<T, AppError> {
+        self.map_err(|e| match e {
+            sqlx::Error::RowNotFound => AppError::NotFound(message.to_string()),
+            e => e.into(),
+        })
+    }
+
+    fn or_conflict(self, message: &str) -> Result<T, AppError> {
+        self.map_err(|e| match e {
+            sqlx::Error::Database(ref db) if db.is_unique_violation() => {
+                AppError::Conflict(message.to_string())
+            },
+            e => e.into(),
+        })
+    }
+
+    fn or_referenced(self, message: &str) -> Result<T, AppError> {
+        self.map_err(|e| match e {
+            sqlx::Error::Database(ref db) if db.is_foreign_key_violation() => {
+                AppError::Conflict(message.to_string())
+            },
+            e => e.into(),
+        })
+    }
+}
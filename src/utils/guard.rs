@@ -0,0 +1,58 @@
+use sqlx::MySqlPool;
+use uuid::Uuid;
+
+// Import claims from utils
+use crate::utils::jwt::Claims;
+
+// Import app error
+use crate::utils::error::{
+    AppError,
+    DbResultExt,
+};
+
+// Guard, make sure the kost belongs to the current user and the room belongs to the kost
+pub async fn room_guard(
+    db: &MySqlPool,
+    claims: &Claims,
+    kost_id: Uuid,
+    room_id: Uuid,
+) -> Result<(), AppError> {
+    kost_guard(db, claims, kost_id).await?;
+
+    sqlx::query!(
+        r#"
+        SELECT id AS "id: Uuid"
+        FROM Rooms
+        WHERE id = ? AND kost_id = ?
+        "#,
+        room_id,
+        kost_id
+    )
+    .fetch_one(db)
+    .await
+    .or_not_found("Room with provided id is not found")?;
+
+    Ok(())
+}
+
+// Guard, make sure the kost belongs to the current user
+pub async fn kost_guard(
+    db: &MySqlPool,
+    claims: &Claims,
+    kost_id: Uuid,
+) -> Result<(), AppError> {
+    sqlx::query!(
+        r#"
+        SELECT id AS "id: Uuid"
+        FROM Kosts
+        WHERE id = ? AND user_id = ?
+        "#,
+        kost_id,
+        claims.sub
+    )
+    .fetch_one(db)
+    .await
+    .or_not_found("Kost with provided id is not found")?;
+
+    Ok(())
+}
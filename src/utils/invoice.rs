@@ -0,0 +1,184 @@
+use chrono::{Datelike, Days, Months, NaiveDate, NaiveDateTime, Utc};
+use sqlx::{MySqlExecutor, MySqlPool};
+use uuid::Uuid;
+
+// Import invoice model
+use crate::models::invoice::Invoice;
+
+// Import payment status enum
+use crate::schemas::booking_schema::PaymentStatus;
+
+// Helper function to get the first day of the billing month of a date
+pub fn billing_period(date: NaiveDate) -> NaiveDate {
+    date.with_day(1).expect("First day of month is always valid")
+}
+
+// Helper function to get the billing month that is running now
+pub fn current_billing_period() -> NaiveDate {
+    billing_period(Utc::now().date_naive())
+}
+
+// Helper function to get the first day of the following billing month
+pub fn next_billing_period(period_start: NaiveDate) -> NaiveDate {
+    period_start
+        .checked_add_months(Months::new(1))
+        .expect("Billing period is out of range")
+}
+
+/*  Invoices are due on the check in day of every month,
+    clamped to the last day for shorter months (check in on the 31st is due on the 30th in April)
+*/
+fn due_date(period_start: NaiveDate, check_in: NaiveDateTime) -> NaiveDate {
+    let last_day = next_billing_period(period_start)
+        .checked_sub_days(Days::new(1))
+        .expect("Billing period is out of range");
+
+    let day = check_in.day().min(last_day.day());
+
+    period_start.with_day(day).unwrap_or(last_day)
+}
+
+// Helper function to generate the invoices of the billing month for every active booking of a kost, or of every kost when None.
+// Returns the number of new invoices, bookings that already have an invoice for the month are skipped
+pub async fn generate_invoices(
+    db: &MySqlPool,
+    kost_id: Option<Uuid>,
+    period_start: NaiveDate,
+) -> Result<u64, sqlx::Error> {
+    let period_end = next_billing_period(period_start);
+
+    // Bookings that are not cancelled and stay at least a part of the billing month
+    let bookings = sqlx::query!(
+        r#"
+        SELECT b.id AS "id: Uuid", b.check_in, b.monthly_rent
+        FROM Bookings b
+        JOIN Rooms r ON r.id = b.room_id
+        WHERE (? IS NULL OR r.kost_id = ?)
+            AND b.payment_status != 'CANCELLED'
+            AND b.check_in < ?
+            AND (b.check_out IS NULL OR b.check_out > ?)
+        "#,
+        kost_id,
+        kost_id,
+        period_end,
+        period_start
+    )
+    .fetch_all(db)
+    .await?;
+
+    let mut generated = 0;
+
+    for booking in bookings {
+        let result = sqlx::query!(
+            "
+            INSERT INTO Invoices (id, booking_id, period_start, amount, due_date)
+            VALUES (?, ?, ?, ?, ?)
+            ON DUPLICATE KEY UPDATE id = id
+            ",
+            Uuid::new_v4(),
+            booking.id,
+            period_start,
+            booking.monthly_rent,
+            due_date(period_start, booking.check_in)
+        )
+        .execute(db)
+        .await?;
+
+        generated += result.rows_affected();
+    }
+
+    Ok(generated)
+}
+
+// Helper to get an invoice of the kost by id
+pub async fn find_invoice(
+    db: &MySqlPool,
+    kost_id: Uuid,
+    invoice_id: Uuid,
+) -> Result<Invoice, sqlx::Error> {
+    sqlx::query_as!(
+        Invoice,
+        r#"
+        SELECT
+            i.id AS "id: Uuid",
+            i.booking_id AS "booking_id: Uuid",
+            b.room_id AS "room_id: Uuid",
+            r.room_number AS "room_number: u32",
+            b.user_id AS "user_id: Uuid",
+            i.period_start,
+            i.amount,
+            i.paid_amount,
+            (i.amount - i.paid_amount) AS "outstanding!: u64",
+            i.due_date,
+            i.status AS "status: PaymentStatus",
+            i.created_at,
+            i.updated_at
+        FROM Invoices i
+        JOIN Bookings b ON b.id = i.booking_id
+        JOIN Rooms r ON r.id = b.room_id
+        WHERE i.id = ? AND r.kost_id = ?
+        "#,
+        invoice_id,
+        kost_id
+    )
+    .fetch_one(db)
+    .await
+}
+
+// Helper function to recompute the paid amount of an invoice from its payments,
+// the invoice becomes PAID once fully covered and falls back to PENDING when a payment is removed
+pub async fn refresh_invoice_balance<'e>(
+    executor: impl MySqlExecutor<'e>,
+    invoice_id: Uuid,
+) -> Result<(), sqlx::Error> {
+    // MySQL assigns from left to right, so the status below sees the new paid_amount
+    sqlx::query!(
+        r#"
+        UPDATE Invoices
+        SET
+            paid_amount = (
+                SELECT COALESCE(SUM(p.amount), 0)
+                FROM Payments p
+                WHERE p.invoice_id = Invoices.id
+            ),
+            status = CASE
+                WHEN status = 'CANCELLED' THEN status
+                WHEN paid_amount >= amount THEN 'PAID'
+                WHEN status = 'PAID' THEN 'PENDING'
+                ELSE status
+            END
+        WHERE id = ?
+        "#,
+        invoice_id
+    )
+    .execute(executor)
+    .await?;
+
+    Ok(())
+}
+
+// Helper function to sync Bookings.payment_status with the invoices of the booking,
+// cancelled bookings and bookings without invoices are left untouched
+pub async fn sync_booking_payment_status<'e>(
+    executor: impl MySqlExecutor<'e>,
+    booking_id: Uuid,
+) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        r#"
+        UPDATE Bookings b
+        SET b.payment_status = CASE
+            WHEN EXISTS (SELECT 1 FROM Invoices i WHERE i.booking_id = b.id AND i.status = 'OVERDUE') THEN 'OVERDUE'
+            WHEN EXISTS (SELECT 1 FROM Invoices i WHERE i.booking_id = b.id AND i.status = 'PENDING') THEN 'PENDING'
+            ELSE 'PAID'
+        END
+        WHERE b.id = ?
+            AND b.payment_status != 'CANCELLED'
+            AND EXISTS (SELECT 1 FROM Invoices i WHERE i.booking_id = b.id AND i.status != 'CANCELLED')
+        "#,
+        booking_id
+    )
+    .execute(executor)
+    .await?;
+
+    Ok(())
+}
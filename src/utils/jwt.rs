@@ -6,19 +6,24 @@ use uuid::Uuid;
 use serde::{Serialize, Deserialize};
 use chrono::{Utc, Duration};
 
+use crate::config::app_config;
+
 #[derive(Serialize, Deserialize, Clone)]
 pub struct Claims {
     pub sub: Uuid,
     pub role: String,
     pub permissions: Vec<String>,
     pub exp: usize,
+    pub jti: Uuid,
+    // Token version of the user when the token was issued
+    pub ver: u32,
 }
 
 //Helper function to generate jwt token
-pub fn generate_token(user_id: Uuid, user_role: String, permissions: Vec<String>) -> Result<String, JwtError> {
-    //Set expiration token to 24 hours
+pub fn generate_token(user_id: Uuid, user_role: String, permissions: Vec<String>, token_version: u32) -> Result<String, JwtError> {
+    //Set expiration token from the config, a new one is issued with the refresh token
     let exp = Utc::now()
-        .checked_add_signed(Duration::hours(24))
+        .checked_add_signed(Duration::minutes(app_config::get().access_token_minutes))
         .unwrap()
         .timestamp() as usize;
 
@@ -30,12 +35,10 @@ pub fn generate_token(user_id: Uuid, user_role: String, permissions: Vec<String>
             role: user_role,
             permissions,
             exp,
+            jti: Uuid::new_v4(),
+            ver: token_version,
         },
-        &EncodingKey::from_secret(
-            std::env::var("JWT_SECRET_KEY")
-                .unwrap_or_else(|_| "kost_management".to_string())
-                .as_ref()
-        )
+        &EncodingKey::from_secret(app_config::get().jwt_secret.as_ref())
     )
 }
 
@@ -44,11 +47,7 @@ pub fn verify_token(token: &str) -> Result<Claims, JwtError> {
     //Decode the token and verify
     let token_data = decode::<Claims>(
         token,
-        &DecodingKey::from_secret(
-            std::env::var("JWT_SECRET_KEY")
-                .unwrap_or_else(|_| "kost_management".to_string())
-                .as_ref()   
-        ),
+        &DecodingKey::from_secret(app_config::get().jwt_secret.as_ref()),
         &Validation::default(),
     )?;
 
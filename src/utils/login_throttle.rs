@@ -0,0 +1,238 @@
+use std::net::IpAddr;
+
+use chrono::{Duration, NaiveDateTime, Utc};
+use serde_json::json;
+use sqlx::MySqlPool;
+use uuid::Uuid;
+
+use crate::config::app_config;
+use crate::utils::audit::record_audit;
+
+// Lockouts are counted again from the start after a day without failed logins
+const LOCKOUT_RESET_HOURS: i64 = 24;
+
+// Helper function to get the seconds until the email or the IP can try to login again, None when neither is locked
+pub async fn locked_for(
+    db: &MySqlPool,
+    email: &str,
+    ip: IpAddr,
+) -> Result<Option<i64>, sqlx::Error> {
+    let locked_until = sqlx::query_scalar!(
+        r#"
+        SELECT MAX(locked_until) AS "locked_until?: NaiveDateTime"
+        FROM Login_Throttles
+        WHERE ((scope = 'email' AND identifier = ?) OR (scope = 'ip' AND identifier = ?))
+            AND locked_until > UTC_TIMESTAMP()
+        "#,
+        email,
+        ip.to_string()
+    )
+    .fetch_one(db)
+    .await?;
+
+    Ok(locked_until.map(seconds_until))
+}
+
+// Helper function to count a failed login for the email and the IP in one transaction.
+// Reaching the limit locks the email or IP, each lockout in a row doubles the lockout time.
+// Returns the seconds the login is locked for when this failure caused a lockout
+pub async fn record_failed_login_tx(
+    db: &MySqlPool,
+    email: &str,
+    ip: IpAddr,
+    user_id: Option<Uuid>,
+) -> Result<Option<i64>, sqlx::Error> {
+    let config = app_config::get();
+    let ip = ip.to_string();
+
+    let scopes = [
+        ("email", email, config.login_max_attempts),
+        ("ip", ip.as_str(), config.login_ip_max_attempts),
+    ];
+
+    let mut tx = db.begin().await?;
+    let mut locked_for = None;
+
+    for (scope, identifier, max_attempts) in scopes {
+        sqlx::query!(
+            r#"
+            INSERT INTO Login_Throttles (scope, identifier, last_failed_at)
+            VALUES (?, ?, UTC_TIMESTAMP())
+            ON DUPLICATE KEY UPDATE identifier = identifier
+            "#,
+            scope,
+            identifier
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        let throttle = sqlx::query!(
+            r#"
+            SELECT failed_count AS "failed_count: u32", lockout_count AS "lockout_count: u32", last_failed_at
+            FROM Login_Throttles
+            WHERE scope = ? AND identifier = ?
+            FOR UPDATE
+            "#,
+            scope,
+            identifier
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let now = Utc::now().naive_utc();
+        let window = Duration::from_std(config.login_attempt_window).unwrap_or_default();
+
+        // Old failures do not count anymore
+        let mut failed_count = if throttle.last_failed_at + window < now {
+            0
+        } else {
+            throttle.failed_count
+        };
+
+        let mut lockout_count = if throttle.last_failed_at + Duration::hours(LOCKOUT_RESET_HOURS) < now {
+            0
+        } else {
+            throttle.lockout_count
+        };
+
+        failed_count += 1;
+
+        let mut locked_until = None;
+
+        if failed_count >= max_attempts {
+            lockout_count += 1;
+            failed_count = 0;
+
+            let lockout = lockout_duration(lockout_count, config.login_lockout_base, config.login_lockout_max);
+            let until = now + lockout;
+            locked_until = Some(until);
+            locked_for = locked_for.max(Some(lockout.num_seconds()));
+
+            // Target is the user for email lockouts, an email that is not registered has no target
+            let (target_type, target_id) = match scope {
+                "email" => ("user", user_id),
+                _ => ("ip", None),
+            };
+
+            record_audit(
+                &mut *tx,
+                None,
+                "login.lockout",
+                target_type,
+                target_id,
+                json!({
+                    "scope": scope,
+                    "identifier": identifier,
+                    "lockout_count": lockout_count,
+                    "locked_until": until,
+                    "locked_seconds": lockout.num_seconds(),
+                }),
+            )
+            .await?;
+        }
+
+        sqlx::query!(
+            r#"
+            UPDATE Login_Throttles
+            SET failed_count = ?, lockout_count = ?, last_failed_at = ?, locked_until = COALESCE(?, locked_until)
+            WHERE scope = ? AND identifier = ?
+            "#,
+            failed_count,
+            lockout_count,
+            now,
+            locked_until,
+            scope,
+            identifier
+        )
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await?;
+
+    Ok(locked_for)
+}
+
+// Helper function to forget the failed logins of the email after a successful login.
+// The IP keeps its count, otherwise logging into an own account would reset it
+pub async fn clear_failed_logins(
+    db: &MySqlPool,
+    email: &str,
+) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        "DELETE FROM Login_Throttles WHERE scope = 'email' AND identifier = ?",
+        email
+    )
+    .execute(db)
+    .await?;
+
+    Ok(())
+}
+
+// Lockout time for the nth lockout in a row, the base time doubled for every earlier lockout
+fn lockout_duration(
+    lockout_count: u32,
+    base: std::time::Duration,
+    max: std::time::Duration,
+) -> Duration {
+    let base = base.as_secs();
+    let max = max.as_secs();
+
+    let seconds = base
+        .checked_mul(1u64 << lockout_count.saturating_sub(1).min(32))
+        .unwrap_or(max)
+        .min(max);
+
+    Duration::seconds(seconds as i64)
+}
+
+// Whole seconds until the time, rounded up so the client never retries too early
+fn seconds_until(until: NaiveDateTime) -> i64 {
+    let remaining = until - Utc::now().naive_utc();
+
+    (remaining.num_milliseconds() + 999).div_euclid(1000).max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BASE: std::time::Duration = std::time::Duration::from_secs(60);
+    const MAX: std::time::Duration = std::time::Duration::from_secs(3600);
+
+    #[test]
+    fn lockout_duration_doubles_for_each_lockout() {
+        assert_eq!(lockout_duration(1, BASE, MAX), Duration::seconds(60));
+        assert_eq!(lockout_duration(2, BASE, MAX), Duration::seconds(120));
+        assert_eq!(lockout_duration(3, BASE, MAX), Duration::seconds(240));
+        assert_eq!(lockout_duration(6, BASE, MAX), Duration::seconds(1920));
+    }
+
+    #[test]
+    fn lockout_duration_stops_at_the_max() {
+        assert_eq!(lockout_duration(7, BASE, MAX), Duration::seconds(3600));
+        assert_eq!(lockout_duration(40, BASE, MAX), Duration::seconds(3600));
+        assert_eq!(lockout_duration(u32::MAX, BASE, MAX), Duration::seconds(3600));
+    }
+
+    #[test]
+    fn lockout_duration_treats_zero_as_the_first_lockout() {
+        assert_eq!(lockout_duration(0, BASE, MAX), Duration::seconds(60));
+    }
+
+    #[test]
+    fn seconds_until_rounds_up() {
+        let now = Utc::now().naive_utc();
+
+        assert_eq!(seconds_until(now + Duration::milliseconds(1500)), 2);
+        assert_eq!(seconds_until(now + Duration::seconds(30)), 30);
+    }
+
+    #[test]
+    fn seconds_until_is_at_least_one() {
+        let now = Utc::now().naive_utc();
+
+        assert_eq!(seconds_until(now), 1);
+        assert_eq!(seconds_until(now - Duration::seconds(10)), 1);
+    }
+}
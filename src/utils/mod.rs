@@ -1,2 +1,15 @@
 pub mod jwt;
-pub mod response;
\ No newline at end of file
+pub mod response;
+pub mod room_vacancy;
+pub mod booking_overlap;
+pub mod invoice;
+pub mod guard;
+pub mod audit;
+pub mod refresh_token;
+pub mod token_version;
+pub mod secure_token;
+pub mod email_verification;
+pub mod login_throttle;
+pub mod client_ip;
+pub mod error;
+pub mod validated_json;
\ No newline at end of file
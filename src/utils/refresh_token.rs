@@ -0,0 +1,61 @@
+use chrono::{Duration, Utc};
+use sqlx::{MySqlExecutor, MySqlPool};
+use uuid::Uuid;
+
+use crate::config::app_config;
+use crate::utils::secure_token::{hash_token, random_token};
+
+// Helper function to store a new refresh token for the user,
+// returns the id of the row and the token to send to the client
+pub async fn issue_refresh_token<'e>(
+    executor: impl MySqlExecutor<'e>,
+    user_id: Uuid,
+) -> Result<(Uuid, String), sqlx::Error> {
+    let token = random_token();
+    let token_id = Uuid::new_v4();
+    let expires_at = Utc::now().naive_utc() + Duration::days(app_config::get().refresh_token_days);
+
+    sqlx::query!(
+        "INSERT INTO Refresh_Tokens (id, user_id, token_hash, expires_at) VALUES (?, ?, ?, ?)",
+        token_id,
+        user_id,
+        hash_token(&token),
+        expires_at
+    )
+    .execute(executor)
+    .await?;
+
+    Ok((token_id, token))
+}
+
+// Helper function to get the role name, permissions and token version of the user, used when a token is issued
+pub async fn user_access(
+    db: &MySqlPool,
+    user_id: Uuid,
+) -> Result<(String, Vec<String>, u32), sqlx::Error> {
+    let role = sqlx::query!(
+        r#"
+        SELECT r.id AS "id: Uuid", r.name, u.token_version AS "token_version: u32"
+        FROM Users u
+        JOIN Roles r ON r.id = u.role_id
+        WHERE u.id = ?
+        "#,
+        user_id
+    )
+    .fetch_one(db)
+    .await?;
+
+    let permissions = sqlx::query_scalar!(
+        r#"
+        SELECT p.name
+        FROM Role_Permissions rp
+        JOIN Permissions p ON p.id = rp.permission_id
+        WHERE rp.role_id = ?
+        "#,
+        role.id
+    )
+    .fetch_all(db)
+    .await?;
+
+    Ok((role.name, permissions, role.token_version))
+}
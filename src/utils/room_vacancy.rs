@@ -0,0 +1,58 @@
+use sqlx::MySqlExecutor;
+use uuid::Uuid;
+
+/*  A booking is open while it is not cancelled and the tenant has not checked out yet,
+    an open booking keeps the room OCCUPIED
+*/
+
+// Helper function to check if the room still has an open booking
+pub async fn has_open_booking<'e>(
+    executor: impl MySqlExecutor<'e>,
+    room_id: Uuid,
+) -> Result<bool, sqlx::Error> {
+    let open_bookings = sqlx::query_scalar!(
+        r#"
+        SELECT COUNT(*) AS "count: i64"
+        FROM Bookings
+        WHERE room_id = ?
+            AND payment_status != 'CANCELLED'
+            AND (check_out IS NULL OR check_out > UTC_TIMESTAMP())
+        "#,
+        room_id
+    )
+    .fetch_one(executor)
+    .await?;
+
+    Ok(open_bookings > 0)
+}
+
+// Helper function to move the room between AVAILABLE and OCCUPIED based on its bookings,
+// rooms under MAINTENANCE are left untouched
+pub async fn sync_room_vacancy<'e>(
+    executor: impl MySqlExecutor<'e>,
+    room_id: Uuid,
+) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        r#"
+        UPDATE Rooms
+        SET room_vacancy = IF(
+            EXISTS (
+                SELECT 1
+                FROM Bookings
+                WHERE room_id = ?
+                    AND payment_status != 'CANCELLED'
+                    AND (check_out IS NULL OR check_out > UTC_TIMESTAMP())
+            ),
+            'OCCUPIED',
+            'AVAILABLE'
+        )
+        WHERE id = ? AND room_vacancy != 'MAINTENANCE'
+        "#,
+        room_id,
+        room_id
+    )
+    .execute(executor)
+    .await?;
+
+    Ok(())
+}
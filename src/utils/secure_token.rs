@@ -0,0 +1,15 @@
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+
+// Helper function to generate a random token to send to the client, 32 bytes hex encoded
+pub fn random_token() -> String {
+    let mut bytes = [0u8; 32];
+    rand::thread_rng().fill_bytes(&mut bytes);
+
+    hex::encode(bytes)
+}
+
+// Helper function to hash a token, only the hash is stored so a leaked table cannot be used
+pub fn hash_token(token: &str) -> String {
+    hex::encode(Sha256::digest(token.as_bytes()))
+}
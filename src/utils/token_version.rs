@@ -0,0 +1,56 @@
+use sqlx::MySqlExecutor;
+use uuid::Uuid;
+
+// Access tokens carry the token version of the user, auth rejects a token once the version moved on.
+// Bump it in the same transaction as the change, so the old permissions stop working right away
+
+// Helper function to invalidate the tokens of every user with the role
+pub async fn bump_role_token_version<'e>(
+    executor: impl MySqlExecutor<'e>,
+    role_id: Uuid,
+) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        "UPDATE Users SET token_version = token_version + 1 WHERE role_id = ?",
+        role_id
+    )
+    .execute(executor)
+    .await?;
+
+    Ok(())
+}
+
+// Helper function to invalidate the tokens of every user whose role has the permission
+pub async fn bump_permission_token_version<'e>(
+    executor: impl MySqlExecutor<'e>,
+    permission_id: Uuid,
+) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        r#"
+        UPDATE Users
+        SET token_version = token_version + 1
+        WHERE role_id IN (
+            SELECT role_id FROM Role_Permissions WHERE permission_id = ?
+        )
+        "#,
+        permission_id
+    )
+    .execute(executor)
+    .await?;
+
+    Ok(())
+}
+
+// Helper function to invalidate the tokens of one user
+pub async fn bump_user_token_version<'e>(
+    executor: impl MySqlExecutor<'e>,
+    user_id: Uuid,
+) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        "UPDATE Users SET token_version = token_version + 1 WHERE id = ?",
+        user_id
+    )
+    .execute(executor)
+    .await?;
+
+    Ok(())
+}
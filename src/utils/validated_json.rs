@@ -0,0 +1,224 @@
+use axum::{
+    Json,
+    body::Bytes,
+    extract::{FromRequest, OptionalFromRequest, Request},
+    http::{header::CONTENT_TYPE, HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+};
+
+use serde::de::DeserializeOwned;
+use serde_json::{
+    error::Category,
+    Value,
+};
+use validator::Validate;
+
+// Import app error
+use crate::utils::error::AppError;
+
+// Import API Response
+use crate::utils::response::ApiResponse;
+
+// JSON body that is deserialized and validated before the handler runs.
+// A wrong content type, invalid JSON and invalid values are answered with the 422 ApiResponse, with the messages per field.
+// A body that cannot be read, e.g. one over the size limit, keeps its status and gets an ApiResponse body too.
+// Option<ValidatedJson<T>> is None when the request has no Content-Type, for endpoints where the body is optional
+pub struct ValidatedJson<T>(pub T);
+
+impl<T, S> FromRequest<S> for ValidatedJson<T>
+where
+    T: DeserializeOwned + Validate,
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        if !has_json_content_type(req.headers()) {
+            return Err(AppError::field(
+                "body",
+                "Request body must be JSON, set the Content-Type header to application/json",
+            ).into_response());
+        }
+
+        let body = Bytes::from_request(req, state)
+            .await
+            .map_err(|rejection| {
+                let message = match rejection.status() {
+                    StatusCode::PAYLOAD_TOO_LARGE => "Request body is too large".to_string(),
+                    _ => rejection.body_text(),
+                };
+
+                (rejection.status(), Json(ApiResponse::<Value>::error(&message))).into_response()
+            })?;
+
+        let deserializer = &mut serde_json::Deserializer::from_slice(&body);
+
+        let value: T = serde_path_to_error::deserialize(deserializer)
+            .map_err(|e| deserialize_error(e).into_response())?;
+
+        value.validate().map_err(|e| AppError::from(e).into_response())?;
+
+        Ok(ValidatedJson(value))
+    }
+}
+
+// Same rule as the Option<Json> extractor of axum, no Content-Type means there is no body
+impl<T, S> OptionalFromRequest<S> for ValidatedJson<T>
+where
+    T: DeserializeOwned + Validate,
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request(req: Request, state: &S) -> Result<Option<Self>, Self::Rejection> {
+        if req.headers().get(CONTENT_TYPE).is_none() {
+            return Ok(None);
+        }
+
+        <Self as FromRequest<S>>::from_request(req, state).await.map(Some)
+    }
+}
+
+// Same check as the Json extractor of axum, application/json or any application/*+json type
+fn has_json_content_type(headers: &HeaderMap) -> bool {
+    let Some(content_type) = headers.get(CONTENT_TYPE).and_then(|value| value.to_str().ok()) else {
+        return false;
+    };
+
+    let mime = content_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+
+    mime == "application/json" || (mime.starts_with("application/") && mime.ends_with("+json"))
+}
+
+// Turn a serde error into a field error, keyed by the path of the value that failed, e.g. room_vacancy
+fn deserialize_error(e: serde_path_to_error::Error<serde_json::Error>) -> AppError {
+    let inner = e.inner();
+
+    if matches!(inner.classify(), Category::Syntax | Category::Eof | Category::Io) {
+        return AppError::field("body", "Request body is not valid JSON");
+    }
+
+    let path = e.path().to_string();
+
+    // Display adds the position in the body, the field name already tells where the problem is
+    let message = inner.to_string();
+    let message = message
+        .strip_suffix(&format!(" at line {} column {}", inner.line(), inner.column()))
+        .unwrap_or(&message);
+
+    // A missing field is reported on the object that should have it
+    if let Some(field) = message
+        .strip_prefix("missing field `")
+        .and_then(|rest| rest.strip_suffix('`'))
+    {
+        let field = match path.as_str() {
+            "." => field.to_string(),
+            parent => format!("{}.{}", parent, field),
+        };
+
+        return AppError::field(&field, "Field is required");
+    }
+
+    let field = match path.as_str() {
+        "." => "body",
+        path => path,
+    };
+
+    AppError::field(field, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use axum::http::HeaderValue;
+    use serde::Deserialize;
+
+    #[allow(dead_code)]
+    #[derive(Debug, Deserialize)]
+    struct Address {
+        city: String,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Debug, Deserialize)]
+    struct Payload {
+        name: String,
+        room_vacancy: u32,
+        address: Option<Address>,
+    }
+
+    fn headers(content_type: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_str(content_type).unwrap());
+        headers
+    }
+
+    // Field errors of the error for a body, panics when the body deserializes
+    fn field_errors(body: &str) -> std::collections::HashMap<String, Vec<String>> {
+        let deserializer = &mut serde_json::Deserializer::from_str(body);
+
+        let e = serde_path_to_error::deserialize::<_, Payload>(deserializer).unwrap_err();
+
+        match deserialize_error(e) {
+            AppError::Validation(errors) => errors,
+            e => panic!("expected a validation error, got {:?}", e),
+        }
+    }
+
+    #[test]
+    fn json_content_types_are_accepted() {
+        assert!(has_json_content_type(&headers("application/json")));
+        assert!(has_json_content_type(&headers("application/json; charset=utf-8")));
+        assert!(has_json_content_type(&headers("Application/JSON")));
+        assert!(has_json_content_type(&headers("application/merge-patch+json")));
+    }
+
+    #[test]
+    fn other_content_types_are_rejected() {
+        assert!(!has_json_content_type(&HeaderMap::new()));
+        assert!(!has_json_content_type(&headers("text/plain")));
+        assert!(!has_json_content_type(&headers("application/x-www-form-urlencoded")));
+        assert!(!has_json_content_type(&headers("text/json")));
+    }
+
+    #[test]
+    fn invalid_json_is_reported_on_the_body() {
+        let errors = field_errors(r#"{"name": "Kost""#);
+        assert_eq!(errors["body"], vec!["Request body is not valid JSON"]);
+
+        let errors = field_errors("not json");
+        assert_eq!(errors["body"], vec!["Request body is not valid JSON"]);
+    }
+
+    #[test]
+    fn missing_fields_are_reported_on_the_field() {
+        let errors = field_errors(r#"{"room_vacancy": 1}"#);
+        assert_eq!(errors["name"], vec!["Field is required"]);
+
+        let errors = field_errors(r#"{"name": "Kost", "room_vacancy": 1, "address": {}}"#);
+        assert_eq!(errors["address.city"], vec!["Field is required"]);
+    }
+
+    #[test]
+    fn wrong_types_are_reported_on_the_field_without_the_position() {
+        let errors = field_errors(r#"{"name": "Kost", "room_vacancy": "two"}"#);
+        let messages = &errors["room_vacancy"];
+
+        assert_eq!(messages.len(), 1);
+        assert!(messages[0].starts_with("invalid type: string \"two\""));
+        assert!(!messages[0].contains(" at line "));
+    }
+
+    #[test]
+    fn a_body_of_the_wrong_type_is_reported_on_the_body() {
+        let errors = field_errors(r#""kost""#);
+        assert_eq!(errors.len(), 1);
+        assert!(errors.contains_key("body"));
+    }
+}